@@ -5,7 +5,7 @@ pub mod block_by_timestamp {
     #![allow(dead_code)]
     use std::result::Result;
     pub const OPERATION_NAME: &str = "BlockByTimestamp";
-    pub const QUERY : & str = "query BlockByTimestamp($timestamp: BigInt!) {\n    blocks(\n    first: 1\n    orderBy: number\n    orderDirection: desc\n    where: {timestamp_lt: $timestamp}\n  ) {\n    id\n    number\n  }\n}" ;
+    pub const QUERY : & str = "query BlockByTimestamp($timestamp: BigInt!) {\n    blocks(\n    first: 1\n    orderBy: number\n    orderDirection: desc\n    where: {timestamp_lt: $timestamp}\n  ) {\n    id\n    number\n    timestamp\n  }\n}" ;
     use super::*;
     use serde::{Deserialize, Serialize};
     #[allow(dead_code)]
@@ -30,6 +30,7 @@ pub mod block_by_timestamp {
     pub struct BlockByTimestampBlocks {
         pub id: ID,
         pub number: String,
+        pub timestamp: String,
     }
 }
 impl graphql_client::GraphQLQuery for BlockByTimestamp {
@@ -0,0 +1,71 @@
+#![allow(clippy::all, warnings)]
+pub struct FactorySnapshotsQuery;
+type BigDecimal = String;
+type BigInt = String;
+pub mod factory_snapshots_query {
+    #![allow(dead_code)]
+    use std::result::Result;
+    pub const OPERATION_NAME: &str = "FactorySnapshotsQuery";
+    pub const QUERY : & str = "query FactorySnapshotsQuery($from: Int!, $skip: Int!, $hourly: Boolean!) {\n  factoryDaySnapshots(\n    first: 1000\n    skip: $skip\n    orderBy: date\n    orderDirection: asc\n    where: { date_gte: $from }\n  ) @skip(if: $hourly) {\n    date\n    volumeUSD\n    feesUSD\n    liquidityUSD\n    transactionCount\n  }\n  factoryHourSnapshots(\n    first: 1000\n    skip: $skip\n    orderBy: date\n    orderDirection: asc\n    where: { date_gte: $from }\n  ) @include(if: $hourly) {\n    date\n    volumeUSD\n    feesUSD\n    liquidityUSD\n    transactionCount\n  }\n}" ;
+    use super::*;
+    use serde::{Deserialize, Serialize};
+    #[allow(dead_code)]
+    type Boolean = bool;
+    #[allow(dead_code)]
+    type Float = f64;
+    #[allow(dead_code)]
+    type Int = i64;
+    #[allow(dead_code)]
+    type ID = String;
+    type BigDecimal = super::BigDecimal;
+    type BigInt = super::BigInt;
+    #[derive(Serialize, Debug)]
+    pub struct Variables {
+        pub from: Int,
+        pub skip: Int,
+        pub hourly: Boolean,
+    }
+    impl Variables {}
+    #[derive(Deserialize, Debug)]
+    pub struct ResponseData {
+        #[serde(rename = "factoryDaySnapshots")]
+        pub factory_day_snapshots: Option<Vec<FactorySnapshotsQueryFactoryDaySnapshots>>,
+        #[serde(rename = "factoryHourSnapshots")]
+        pub factory_hour_snapshots: Option<Vec<FactorySnapshotsQueryFactoryHourSnapshots>>,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct FactorySnapshotsQueryFactoryDaySnapshots {
+        pub date: Int,
+        #[serde(rename = "volumeUSD")]
+        pub volume_usd: BigDecimal,
+        #[serde(rename = "feesUSD")]
+        pub fees_usd: BigDecimal,
+        #[serde(rename = "liquidityUSD")]
+        pub liquidity_usd: BigDecimal,
+        #[serde(rename = "transactionCount")]
+        pub transaction_count: BigInt,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct FactorySnapshotsQueryFactoryHourSnapshots {
+        pub date: Int,
+        #[serde(rename = "volumeUSD")]
+        pub volume_usd: BigDecimal,
+        #[serde(rename = "feesUSD")]
+        pub fees_usd: BigDecimal,
+        #[serde(rename = "liquidityUSD")]
+        pub liquidity_usd: BigDecimal,
+        #[serde(rename = "transactionCount")]
+        pub transaction_count: BigInt,
+    }
+}
+impl graphql_client::GraphQLQuery for FactorySnapshotsQuery {
+    type Variables = factory_snapshots_query::Variables;
+    type ResponseData = factory_snapshots_query::ResponseData;
+    fn build_query(variables: Self::Variables) -> ::graphql_client::QueryBody<Self::Variables> {
+        graphql_client::QueryBody {
+            variables,
+            query: factory_snapshots_query::QUERY,
+            operation_name: factory_snapshots_query::OPERATION_NAME,
+        }
+    }
+}
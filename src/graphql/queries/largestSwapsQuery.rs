@@ -0,0 +1,50 @@
+#![allow(clippy::all, warnings)]
+pub struct LargestSwapsQuery;
+type BigDecimal = String;
+type BigInt = String;
+pub mod largest_swaps_query {
+    #![allow(dead_code)]
+    use std::result::Result;
+    pub const OPERATION_NAME: &str = "LargestSwapsQuery";
+    pub const QUERY : & str = "query LargestSwapsQuery($pair: String!, $from: BigInt!, $until: BigInt!) {\n  swaps(\n    first: 1000\n    orderBy: amountUSD\n    orderDirection: desc\n    where: { pair: $pair, timestamp_gte: $from, timestamp_lte: $until }\n  ) {\n    to\n    amountUSD\n  }\n}" ;
+    use super::*;
+    use serde::{Deserialize, Serialize};
+    #[allow(dead_code)]
+    type Boolean = bool;
+    #[allow(dead_code)]
+    type Float = f64;
+    #[allow(dead_code)]
+    type Int = i64;
+    #[allow(dead_code)]
+    type ID = String;
+    type BigDecimal = super::BigDecimal;
+    type BigInt = super::BigInt;
+    #[derive(Serialize, Debug)]
+    pub struct Variables {
+        pub pair: String,
+        pub from: BigInt,
+        pub until: BigInt,
+    }
+    impl Variables {}
+    #[derive(Deserialize, Debug)]
+    pub struct ResponseData {
+        pub swaps: Vec<LargestSwapsQuerySwaps>,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct LargestSwapsQuerySwaps {
+        pub to: String,
+        #[serde(rename = "amountUSD")]
+        pub amount_usd: BigDecimal,
+    }
+}
+impl graphql_client::GraphQLQuery for LargestSwapsQuery {
+    type Variables = largest_swaps_query::Variables;
+    type ResponseData = largest_swaps_query::ResponseData;
+    fn build_query(variables: Self::Variables) -> ::graphql_client::QueryBody<Self::Variables> {
+        graphql_client::QueryBody {
+            variables,
+            query: largest_swaps_query::QUERY,
+            operation_name: largest_swaps_query::OPERATION_NAME,
+        }
+    }
+}
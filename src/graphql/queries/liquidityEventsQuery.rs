@@ -0,0 +1,80 @@
+#![allow(clippy::all, warnings)]
+pub struct LiquidityEventsQuery;
+type BigDecimal = String;
+type BigInt = String;
+pub mod liquidity_events_query {
+    #![allow(dead_code)]
+    use std::result::Result;
+    pub const OPERATION_NAME: &str = "LiquidityEventsQuery";
+    pub const QUERY : & str = "query LiquidityEventsQuery($mintCursor: BigInt!, $lastMint: ID!, $burnCursor: BigInt!, $lastBurn: ID!, $withMints: Boolean!, $withBurns: Boolean!) {\n  mints(\n    first: 1000\n    orderBy: timestamp\n    orderDirection: asc\n    where: { or: [{ timestamp_gt: $mintCursor }, { timestamp: $mintCursor, id_gt: $lastMint }] }\n  ) @include(if: $withMints) {\n    id\n    timestamp\n    pair {\n      id\n      name\n    }\n    amountUSD\n  }\n  burns(\n    first: 1000\n    orderBy: timestamp\n    orderDirection: asc\n    where: { or: [{ timestamp_gt: $burnCursor }, { timestamp: $burnCursor, id_gt: $lastBurn }] }\n  ) @include(if: $withBurns) {\n    id\n    timestamp\n    pair {\n      id\n      name\n    }\n    amountUSD\n  }\n}" ;
+    use super::*;
+    use serde::{Deserialize, Serialize};
+    #[allow(dead_code)]
+    type Boolean = bool;
+    #[allow(dead_code)]
+    type Float = f64;
+    #[allow(dead_code)]
+    type Int = i64;
+    #[allow(dead_code)]
+    type ID = String;
+    type BigDecimal = super::BigDecimal;
+    type BigInt = super::BigInt;
+    #[derive(Serialize, Debug)]
+    pub struct Variables {
+        #[serde(rename = "mintCursor")]
+        pub mint_cursor: BigInt,
+        #[serde(rename = "lastMint")]
+        pub last_mint: ID,
+        #[serde(rename = "burnCursor")]
+        pub burn_cursor: BigInt,
+        #[serde(rename = "lastBurn")]
+        pub last_burn: ID,
+        #[serde(rename = "withMints")]
+        pub with_mints: Boolean,
+        #[serde(rename = "withBurns")]
+        pub with_burns: Boolean,
+    }
+    impl Variables {}
+    #[derive(Deserialize, Debug)]
+    pub struct ResponseData {
+        pub mints: Option<Vec<LiquidityEventsQueryMints>>,
+        pub burns: Option<Vec<LiquidityEventsQueryBurns>>,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct LiquidityEventsQueryMints {
+        pub id: ID,
+        pub timestamp: BigInt,
+        pub pair: LiquidityEventsQueryMintsPair,
+        #[serde(rename = "amountUSD")]
+        pub amount_usd: Option<BigDecimal>,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct LiquidityEventsQueryMintsPair {
+        pub id: ID,
+        pub name: String,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct LiquidityEventsQueryBurns {
+        pub id: ID,
+        pub timestamp: BigInt,
+        pub pair: LiquidityEventsQueryBurnsPair,
+        #[serde(rename = "amountUSD")]
+        pub amount_usd: Option<BigDecimal>,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct LiquidityEventsQueryBurnsPair {
+        pub id: ID,
+        pub name: String,
+    }
+}
+impl graphql_client::GraphQLQuery for LiquidityEventsQuery {
+    type Variables = liquidity_events_query::Variables;
+    type ResponseData = liquidity_events_query::ResponseData;
+    fn build_query(variables: Self::Variables) -> ::graphql_client::QueryBody<Self::Variables> {
+        graphql_client::QueryBody {
+            variables,
+            query: liquidity_events_query::QUERY,
+            operation_name: liquidity_events_query::OPERATION_NAME,
+        }
+    }
+}
@@ -0,0 +1,45 @@
+#![allow(clippy::all, warnings)]
+pub struct LiquidityPositionsQuery;
+pub mod liquidity_positions_query {
+    #![allow(dead_code)]
+    use std::result::Result;
+    pub const OPERATION_NAME: &str = "LiquidityPositionsQuery";
+    pub const QUERY : & str = "query LiquidityPositionsQuery($pair: String!, $lastId: ID!) {\n  liquidityPositions(\n    first: 1000\n    orderBy: id\n    orderDirection: asc\n    where: { pair: $pair, balance_gt: 0, id_gt: $lastId }\n  ) {\n    id\n  }\n}" ;
+    use super::*;
+    use serde::{Deserialize, Serialize};
+    #[allow(dead_code)]
+    type Boolean = bool;
+    #[allow(dead_code)]
+    type Float = f64;
+    #[allow(dead_code)]
+    type Int = i64;
+    #[allow(dead_code)]
+    type ID = String;
+    #[derive(Serialize, Debug)]
+    pub struct Variables {
+        pub pair: String,
+        #[serde(rename = "lastId")]
+        pub last_id: ID,
+    }
+    impl Variables {}
+    #[derive(Deserialize, Debug)]
+    pub struct ResponseData {
+        #[serde(rename = "liquidityPositions")]
+        pub liquidity_positions: Vec<LiquidityPositionsQueryLiquidityPositions>,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct LiquidityPositionsQueryLiquidityPositions {
+        pub id: ID,
+    }
+}
+impl graphql_client::GraphQLQuery for LiquidityPositionsQuery {
+    type Variables = liquidity_positions_query::Variables;
+    type ResponseData = liquidity_positions_query::ResponseData;
+    fn build_query(variables: Self::Variables) -> ::graphql_client::QueryBody<Self::Variables> {
+        graphql_client::QueryBody {
+            variables,
+            query: liquidity_positions_query::QUERY,
+            operation_name: liquidity_positions_query::OPERATION_NAME,
+        }
+    }
+}
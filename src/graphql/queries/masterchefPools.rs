@@ -1,12 +1,13 @@
 #![allow(clippy::all, warnings)]
 pub struct MasterchefPools;
+type BigDecimal = String;
 type BigInt = String;
 type Bytes = String;
 pub mod masterchef_pools {
     #![allow(dead_code)]
     use std::result::Result;
     pub const OPERATION_NAME: &str = "masterchefPools";
-    pub const QUERY : & str = "query masterchefPools{\n  masterChefs {\n    id\n    sushiPerBlock\n    totalAllocPoint\n  }\n  pools(\n    first: 1000\n    orderBy: allocPoint\n    orderDirection: desc\n    where: {allocPoint_gt: 0}\n  ) {\n    id\n    pair\n    allocPoint\n  }\n}" ;
+    pub const QUERY : & str = "query masterchefPools($block: Block_height) {\n  masterChefs(block: $block) {\n    id\n    sushiPerBlock\n    totalAllocPoint\n  }\n  pools(\n    first: 1000\n    orderBy: allocPoint\n    orderDirection: desc\n    where: {allocPoint_gt: 0}\n    block: $block\n  ) {\n    id\n    pair\n    allocPoint\n    slpBalance\n  }\n}" ;
     use super::*;
     use serde::{Deserialize, Serialize};
     #[allow(dead_code)]
@@ -17,10 +18,20 @@ pub mod masterchef_pools {
     type Int = i64;
     #[allow(dead_code)]
     type ID = String;
+    type BigDecimal = super::BigDecimal;
     type BigInt = super::BigInt;
     type Bytes = super::Bytes;
-    #[derive(Serialize)]
-    pub struct Variables;
+    #[derive(Serialize, Debug)]
+    pub struct Block_height {
+        pub hash: Option<Bytes>,
+        pub number: Option<Int>,
+        pub number_gte: Option<Int>,
+    }
+    #[derive(Serialize, Debug)]
+    pub struct Variables {
+        pub block: Option<Block_height>,
+    }
+    impl Variables {}
     #[derive(Deserialize, Debug)]
     pub struct ResponseData {
         #[serde(rename = "masterChefs")]
@@ -41,6 +52,8 @@ pub mod masterchef_pools {
         pub pair: Bytes,
         #[serde(rename = "allocPoint")]
         pub alloc_point: BigInt,
+        #[serde(rename = "slpBalance")]
+        pub slp_balance: BigDecimal,
     }
 }
 impl graphql_client::GraphQLQuery for MasterchefPools {
@@ -6,7 +6,7 @@ pub mod masterchefv2_pools {
     #![allow(dead_code)]
     use std::result::Result;
     pub const OPERATION_NAME: &str = "masterChefv2Pools";
-    pub const QUERY : & str = "query masterChefv2Pools {\n  masterChefs {\n    id\n    totalAllocPoint\n  }\n  pools(\n    first: 1000\n    orderBy: allocPoint\n    orderDirection: desc\n    where: {allocPoint_gt: 0}\n  ) {\n    id\n    pair\n    allocPoint\n  }\n}" ;
+    pub const QUERY : & str = "query masterChefv2Pools($block: Block_height) {\n  masterChefs(block: $block) {\n    id\n    totalAllocPoint\n  }\n  pools(\n    first: 1000\n    orderBy: allocPoint\n    orderDirection: desc\n    where: {allocPoint_gt: 0}\n    block: $block\n  ) {\n    id\n    pair\n    allocPoint\n    slpBalance\n  }\n}" ;
     use super::*;
     use serde::{Deserialize, Serialize};
     #[allow(dead_code)]
@@ -19,8 +19,17 @@ pub mod masterchefv2_pools {
     type ID = String;
     type BigInt = super::BigInt;
     type Bytes = super::Bytes;
-    #[derive(Serialize)]
-    pub struct Variables;
+    #[derive(Serialize, Debug)]
+    pub struct Block_height {
+        pub hash: Option<Bytes>,
+        pub number: Option<Int>,
+        pub number_gte: Option<Int>,
+    }
+    #[derive(Serialize, Debug)]
+    pub struct Variables {
+        pub block: Option<Block_height>,
+    }
+    impl Variables {}
     #[derive(Deserialize, Debug)]
     pub struct ResponseData {
         #[serde(rename = "masterChefs")]
@@ -39,6 +48,8 @@ pub mod masterchefv2_pools {
         pub pair: Bytes,
         #[serde(rename = "allocPoint")]
         pub alloc_point: BigInt,
+        #[serde(rename = "slpBalance")]
+        pub slp_balance: BigInt,
     }
 }
 impl graphql_client::GraphQLQuery for MasterChefv2Pools {
@@ -8,7 +8,7 @@ pub mod minichef_pools {
     #![allow(dead_code)]
     use std::result::Result;
     pub const OPERATION_NAME: &str = "farms";
-    pub const QUERY : & str = "query farms {\n  miniChefs {\n    id\n    sushiPerSecond\n    totalAllocPoint\n  }\n  pools(first: 1000, where: {allocPoint_gt: 0}) {\n    id\n    pair\n    allocPoint\n  }\n}" ;
+    pub const QUERY : & str = "query farms($block: Block_height) {\n  miniChefs(block: $block) {\n    id\n    sushiPerSecond\n    totalAllocPoint\n  }\n  pools(first: 1000, where: {allocPoint_gt: 0}, block: $block) {\n    id\n    pair\n    allocPoint\n    slpBalance\n  }\n}" ;
     use super::*;
     use serde::{Deserialize, Serialize};
     #[allow(dead_code)]
@@ -21,8 +21,17 @@ pub mod minichef_pools {
     type ID = String;
     type BigInt = super::BigInt;
     type Bytes = super::Bytes;
-    #[derive(Serialize)]
-    pub struct Variables;
+    #[derive(Serialize, Debug)]
+    pub struct Block_height {
+        pub hash: Option<Bytes>,
+        pub number: Option<Int>,
+        pub number_gte: Option<Int>,
+    }
+    #[derive(Serialize, Debug)]
+    pub struct Variables {
+        pub block: Option<Block_height>,
+    }
+    impl Variables {}
     #[derive(Deserialize, Debug)]
     pub struct ResponseData {
         #[serde(rename = "miniChefs")]
@@ -43,6 +52,8 @@ pub mod minichef_pools {
         pub pair: Bytes,
         #[serde(rename = "allocPoint")]
         pub alloc_point: BigInt,
+        #[serde(rename = "slpBalance")]
+        pub slp_balance: BigInt,
     }
 }
 impl graphql_client::GraphQLQuery for MinichefPools {
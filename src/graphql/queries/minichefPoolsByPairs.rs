@@ -0,0 +1,51 @@
+#![allow(clippy::all, warnings)]
+pub struct MinichefPoolsByPairs;
+type BigInt = String;
+type Bytes = String;
+pub mod minichef_pools_by_pairs {
+    #![allow(dead_code)]
+    use std::result::Result;
+    pub const OPERATION_NAME: &str = "MinichefPoolsByPairs";
+    pub const QUERY : & str = "query MinichefPoolsByPairs($pairs: [Bytes!]!) {\n  pools(first: 1000, where: {pair_in: $pairs}) {\n    id\n    pair\n    allocPoint\n    slpBalance\n  }\n}" ;
+    use super::*;
+    use serde::{Deserialize, Serialize};
+    #[allow(dead_code)]
+    type Boolean = bool;
+    #[allow(dead_code)]
+    type Float = f64;
+    #[allow(dead_code)]
+    type Int = i64;
+    #[allow(dead_code)]
+    type ID = String;
+    type BigInt = super::BigInt;
+    type Bytes = super::Bytes;
+    #[derive(Serialize, Debug)]
+    pub struct Variables {
+        pub pairs: Vec<Bytes>,
+    }
+    impl Variables {}
+    #[derive(Deserialize, Debug)]
+    pub struct ResponseData {
+        pub pools: Vec<MinichefPoolsByPairsPools>,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct MinichefPoolsByPairsPools {
+        pub id: ID,
+        pub pair: Bytes,
+        #[serde(rename = "allocPoint")]
+        pub alloc_point: BigInt,
+        #[serde(rename = "slpBalance")]
+        pub slp_balance: BigInt,
+    }
+}
+impl graphql_client::GraphQLQuery for MinichefPoolsByPairs {
+    type Variables = minichef_pools_by_pairs::Variables;
+    type ResponseData = minichef_pools_by_pairs::ResponseData;
+    fn build_query(variables: Self::Variables) -> ::graphql_client::QueryBody<Self::Variables> {
+        graphql_client::QueryBody {
+            variables,
+            query: minichef_pools_by_pairs::QUERY,
+            operation_name: minichef_pools_by_pairs::OPERATION_NAME,
+        }
+    }
+}
@@ -0,0 +1,62 @@
+#![allow(clippy::all, warnings)]
+pub struct MinichefRewards;
+type BigInt = String;
+type Bytes = String;
+pub mod minichef_rewards {
+    #![allow(dead_code)]
+    use std::result::Result;
+    pub const OPERATION_NAME: &str = "minichefRewards";
+    pub const QUERY : & str = "query minichefRewards {\n  miniChefs {\n    id\n    sushi\n    sushiPerSecond\n    totalAllocPoint\n  }\n  pools(first: 1000, where: {slpBalance_gt: 0}) {\n    id\n    allocPoint\n    accSushiPerShare\n    lastRewardTime\n    slpBalance\n  }\n}" ;
+    use super::*;
+    use serde::{Deserialize, Serialize};
+    #[allow(dead_code)]
+    type Boolean = bool;
+    #[allow(dead_code)]
+    type Float = f64;
+    #[allow(dead_code)]
+    type Int = i64;
+    #[allow(dead_code)]
+    type ID = String;
+    type BigInt = super::BigInt;
+    type Bytes = super::Bytes;
+    #[derive(Serialize)]
+    pub struct Variables;
+    #[derive(Deserialize, Debug)]
+    pub struct ResponseData {
+        #[serde(rename = "miniChefs")]
+        pub mini_chefs: Vec<MinichefRewardsMiniChefs>,
+        pub pools: Vec<MinichefRewardsPools>,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct MinichefRewardsMiniChefs {
+        pub id: ID,
+        pub sushi: Bytes,
+        #[serde(rename = "sushiPerSecond")]
+        pub sushi_per_second: BigInt,
+        #[serde(rename = "totalAllocPoint")]
+        pub total_alloc_point: BigInt,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct MinichefRewardsPools {
+        pub id: ID,
+        #[serde(rename = "allocPoint")]
+        pub alloc_point: BigInt,
+        #[serde(rename = "accSushiPerShare")]
+        pub acc_sushi_per_share: BigInt,
+        #[serde(rename = "lastRewardTime")]
+        pub last_reward_time: BigInt,
+        #[serde(rename = "slpBalance")]
+        pub slp_balance: BigInt,
+    }
+}
+impl graphql_client::GraphQLQuery for MinichefRewards {
+    type Variables = minichef_rewards::Variables;
+    type ResponseData = minichef_rewards::ResponseData;
+    fn build_query(variables: Self::Variables) -> ::graphql_client::QueryBody<Self::Variables> {
+        graphql_client::QueryBody {
+            variables,
+            query: minichef_rewards::QUERY,
+            operation_name: minichef_rewards::OPERATION_NAME,
+        }
+    }
+}
@@ -0,0 +1,53 @@
+#![allow(clippy::all, warnings)]
+pub struct MinichefUsers;
+type BigInt = String;
+pub mod minichef_users {
+    #![allow(dead_code)]
+    use std::result::Result;
+    pub const OPERATION_NAME: &str = "minichefUsers";
+    pub const QUERY : & str = "query minichefUsers($lastId: ID!) {\n  users(\n    first: 1000\n    orderBy: id\n    orderDirection: asc\n    where: {amount_gt: 0, id_gt: $lastId}\n  ) {\n    id\n    pool {\n      id\n    }\n    amount\n    rewardDebt\n  }\n}" ;
+    use super::*;
+    use serde::{Deserialize, Serialize};
+    #[allow(dead_code)]
+    type Boolean = bool;
+    #[allow(dead_code)]
+    type Float = f64;
+    #[allow(dead_code)]
+    type Int = i64;
+    #[allow(dead_code)]
+    type ID = String;
+    type BigInt = super::BigInt;
+    #[derive(Serialize)]
+    pub struct Variables {
+        #[serde(rename = "lastId")]
+        pub last_id: ID,
+    }
+    impl Variables {}
+    #[derive(Deserialize, Debug)]
+    pub struct ResponseData {
+        pub users: Vec<MinichefUsersUsers>,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct MinichefUsersUsers {
+        pub id: ID,
+        pub pool: Option<MinichefUsersUsersPool>,
+        pub amount: BigInt,
+        #[serde(rename = "rewardDebt")]
+        pub reward_debt: BigInt,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct MinichefUsersUsersPool {
+        pub id: ID,
+    }
+}
+impl graphql_client::GraphQLQuery for MinichefUsers {
+    type Variables = minichef_users::Variables;
+    type ResponseData = minichef_users::ResponseData;
+    fn build_query(variables: Self::Variables) -> ::graphql_client::QueryBody<Self::Variables> {
+        graphql_client::QueryBody {
+            variables,
+            query: minichef_users::QUERY,
+            operation_name: minichef_users::OPERATION_NAME,
+        }
+    }
+}
@@ -1,5 +1,21 @@
 pub mod blockByTimestamp;
+pub mod factorySnapshotsQuery;
+pub mod largestSwapsQuery;
+pub mod liquidityEventsQuery;
+pub mod liquidityPositionsQuery;
 pub mod masterchefPools;
 pub mod masterchefv2Pools;
 pub mod minichefPools;
+pub mod minichefPoolsByPairs;
+pub mod minichefRewards;
+pub mod minichefUsers;
+pub mod pairDetailQuery;
+pub mod pairSnapshotsQuery;
+pub mod pairsBySymbolsQuery;
+pub mod pairsDaySnapshotsQuery;
+pub mod pairsStateQuery;
 pub mod periodVolumeQuery;
+pub mod subgraphHeadQuery;
+pub mod swapsQuery;
+pub mod v3PoolsQuery;
+pub mod v3PoolsStateQuery;
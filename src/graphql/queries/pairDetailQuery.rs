@@ -0,0 +1,96 @@
+#![allow(clippy::all, warnings)]
+pub struct PairDetailQuery;
+type BigDecimal = String;
+type BigInt = String;
+pub mod pair_detail_query {
+    #![allow(dead_code)]
+    use std::result::Result;
+    pub const OPERATION_NAME: &str = "PairDetailQuery";
+    pub const QUERY : & str = "query PairDetailQuery($id: ID!, $days: Int!) {\n  pair(id: $id) {\n    id\n    name\n    source\n    swapFee\n    token0 {\n      id\n      symbol\n      decimals\n    }\n    token1 {\n      id\n      symbol\n      decimals\n    }\n    reserve0\n    reserve1\n    liquidity\n    liquidityUSD\n    token0Price\n    token1Price\n    volumeUSD\n    feesUSD\n    txCount\n    createdAtTimestamp\n    daySnapshots(first: $days, orderBy: date, orderDirection: desc) {\n      date\n      volumeUSD\n      feesUSD\n      liquidityUSD\n      transactionCount\n    }\n  }\n}" ;
+    use super::*;
+    use serde::{Deserialize, Serialize};
+    #[allow(dead_code)]
+    type Boolean = bool;
+    #[allow(dead_code)]
+    type Float = f64;
+    #[allow(dead_code)]
+    type Int = i64;
+    #[allow(dead_code)]
+    type ID = String;
+    type BigDecimal = super::BigDecimal;
+    type BigInt = super::BigInt;
+    #[derive(Serialize, Debug)]
+    pub struct Variables {
+        pub id: ID,
+        pub days: Int,
+    }
+    impl Variables {}
+    #[derive(Deserialize, Debug)]
+    pub struct ResponseData {
+        pub pair: Option<PairDetailQueryPair>,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct PairDetailQueryPair {
+        pub id: ID,
+        pub name: String,
+        pub source: String,
+        #[serde(rename = "swapFee")]
+        pub swap_fee: BigInt,
+        pub token0: PairDetailQueryPairToken0,
+        pub token1: PairDetailQueryPairToken1,
+        pub reserve0: BigInt,
+        pub reserve1: BigInt,
+        pub liquidity: BigInt,
+        #[serde(rename = "liquidityUSD")]
+        pub liquidity_usd: BigDecimal,
+        #[serde(rename = "token0Price")]
+        pub token0_price: BigDecimal,
+        #[serde(rename = "token1Price")]
+        pub token1_price: BigDecimal,
+        #[serde(rename = "volumeUSD")]
+        pub volume_usd: BigDecimal,
+        #[serde(rename = "feesUSD")]
+        pub fees_usd: BigDecimal,
+        #[serde(rename = "txCount")]
+        pub tx_count: BigInt,
+        #[serde(rename = "createdAtTimestamp")]
+        pub created_at_timestamp: BigInt,
+        #[serde(rename = "daySnapshots")]
+        pub day_snapshots: Vec<PairDetailQueryPairDaySnapshots>,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct PairDetailQueryPairToken0 {
+        pub id: ID,
+        pub symbol: String,
+        pub decimals: BigInt,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct PairDetailQueryPairToken1 {
+        pub id: ID,
+        pub symbol: String,
+        pub decimals: BigInt,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct PairDetailQueryPairDaySnapshots {
+        pub date: Int,
+        #[serde(rename = "volumeUSD")]
+        pub volume_usd: BigDecimal,
+        #[serde(rename = "feesUSD")]
+        pub fees_usd: BigDecimal,
+        #[serde(rename = "liquidityUSD")]
+        pub liquidity_usd: BigDecimal,
+        #[serde(rename = "transactionCount")]
+        pub transaction_count: BigInt,
+    }
+}
+impl graphql_client::GraphQLQuery for PairDetailQuery {
+    type Variables = pair_detail_query::Variables;
+    type ResponseData = pair_detail_query::ResponseData;
+    fn build_query(variables: Self::Variables) -> ::graphql_client::QueryBody<Self::Variables> {
+        graphql_client::QueryBody {
+            variables,
+            query: pair_detail_query::QUERY,
+            operation_name: pair_detail_query::OPERATION_NAME,
+        }
+    }
+}
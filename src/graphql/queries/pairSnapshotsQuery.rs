@@ -0,0 +1,72 @@
+#![allow(clippy::all, warnings)]
+pub struct PairSnapshotsQuery;
+type BigDecimal = String;
+type BigInt = String;
+pub mod pair_snapshots_query {
+    #![allow(dead_code)]
+    use std::result::Result;
+    pub const OPERATION_NAME: &str = "PairSnapshotsQuery";
+    pub const QUERY : & str = "query PairSnapshotsQuery($pair: String!, $from: Int!, $skip: Int!, $hourly: Boolean!) {\n  pairDaySnapshots(\n    first: 1000\n    skip: $skip\n    orderBy: date\n    orderDirection: asc\n    where: { pair: $pair, date_gte: $from }\n  ) @skip(if: $hourly) {\n    date\n    volumeUSD\n    feesUSD\n    liquidityUSD\n    transactionCount\n  }\n  pairHourSnapshots(\n    first: 1000\n    skip: $skip\n    orderBy: date\n    orderDirection: asc\n    where: { pair: $pair, date_gte: $from }\n  ) @include(if: $hourly) {\n    date\n    volumeUSD\n    feesUSD\n    liquidityUSD\n    transactionCount\n  }\n}" ;
+    use super::*;
+    use serde::{Deserialize, Serialize};
+    #[allow(dead_code)]
+    type Boolean = bool;
+    #[allow(dead_code)]
+    type Float = f64;
+    #[allow(dead_code)]
+    type Int = i64;
+    #[allow(dead_code)]
+    type ID = String;
+    type BigDecimal = super::BigDecimal;
+    type BigInt = super::BigInt;
+    #[derive(Serialize, Debug)]
+    pub struct Variables {
+        pub pair: String,
+        pub from: Int,
+        pub skip: Int,
+        pub hourly: Boolean,
+    }
+    impl Variables {}
+    #[derive(Deserialize, Debug)]
+    pub struct ResponseData {
+        #[serde(rename = "pairDaySnapshots")]
+        pub pair_day_snapshots: Option<Vec<PairSnapshotsQueryPairDaySnapshots>>,
+        #[serde(rename = "pairHourSnapshots")]
+        pub pair_hour_snapshots: Option<Vec<PairSnapshotsQueryPairHourSnapshots>>,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct PairSnapshotsQueryPairDaySnapshots {
+        pub date: Int,
+        #[serde(rename = "volumeUSD")]
+        pub volume_usd: BigDecimal,
+        #[serde(rename = "feesUSD")]
+        pub fees_usd: BigDecimal,
+        #[serde(rename = "liquidityUSD")]
+        pub liquidity_usd: BigDecimal,
+        #[serde(rename = "transactionCount")]
+        pub transaction_count: BigInt,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct PairSnapshotsQueryPairHourSnapshots {
+        pub date: Int,
+        #[serde(rename = "volumeUSD")]
+        pub volume_usd: BigDecimal,
+        #[serde(rename = "feesUSD")]
+        pub fees_usd: BigDecimal,
+        #[serde(rename = "liquidityUSD")]
+        pub liquidity_usd: BigDecimal,
+        #[serde(rename = "transactionCount")]
+        pub transaction_count: BigInt,
+    }
+}
+impl graphql_client::GraphQLQuery for PairSnapshotsQuery {
+    type Variables = pair_snapshots_query::Variables;
+    type ResponseData = pair_snapshots_query::ResponseData;
+    fn build_query(variables: Self::Variables) -> ::graphql_client::QueryBody<Self::Variables> {
+        graphql_client::QueryBody {
+            variables,
+            query: pair_snapshots_query::QUERY,
+            operation_name: pair_snapshots_query::OPERATION_NAME,
+        }
+    }
+}
@@ -0,0 +1,57 @@
+#![allow(clippy::all, warnings)]
+pub struct PairsBySymbolsQuery;
+type BigDecimal = String;
+pub mod pairs_by_symbols_query {
+    #![allow(dead_code)]
+    use std::result::Result;
+    pub const OPERATION_NAME: &str = "PairsBySymbolsQuery";
+    pub const QUERY : & str = "query PairsBySymbolsQuery($symbols: [String!]!) {\n  pairs(\n    first: 100\n    orderBy: liquidityUSD\n    orderDirection: desc\n    where: { token0_: { symbol_in: $symbols }, token1_: { symbol_in: $symbols } }\n  ) {\n    id\n    name\n    token0 {\n      symbol\n    }\n    token1 {\n      symbol\n    }\n    liquidityUSD\n  }\n}" ;
+    use super::*;
+    use serde::{Deserialize, Serialize};
+    #[allow(dead_code)]
+    type Boolean = bool;
+    #[allow(dead_code)]
+    type Float = f64;
+    #[allow(dead_code)]
+    type Int = i64;
+    #[allow(dead_code)]
+    type ID = String;
+    type BigDecimal = super::BigDecimal;
+    #[derive(Serialize, Debug)]
+    pub struct Variables {
+        pub symbols: Vec<String>,
+    }
+    impl Variables {}
+    #[derive(Deserialize, Debug)]
+    pub struct ResponseData {
+        pub pairs: Vec<PairsBySymbolsQueryPairs>,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct PairsBySymbolsQueryPairs {
+        pub id: ID,
+        pub name: String,
+        pub token0: PairsBySymbolsQueryPairsToken0,
+        pub token1: PairsBySymbolsQueryPairsToken1,
+        #[serde(rename = "liquidityUSD")]
+        pub liquidity_usd: BigDecimal,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct PairsBySymbolsQueryPairsToken0 {
+        pub symbol: String,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct PairsBySymbolsQueryPairsToken1 {
+        pub symbol: String,
+    }
+}
+impl graphql_client::GraphQLQuery for PairsBySymbolsQuery {
+    type Variables = pairs_by_symbols_query::Variables;
+    type ResponseData = pairs_by_symbols_query::ResponseData;
+    fn build_query(variables: Self::Variables) -> ::graphql_client::QueryBody<Self::Variables> {
+        graphql_client::QueryBody {
+            variables,
+            query: pairs_by_symbols_query::QUERY,
+            operation_name: pairs_by_symbols_query::OPERATION_NAME,
+        }
+    }
+}
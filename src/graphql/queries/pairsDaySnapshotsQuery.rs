@@ -0,0 +1,56 @@
+#![allow(clippy::all, warnings)]
+pub struct PairsDaySnapshotsQuery;
+type BigDecimal = String;
+pub mod pairs_day_snapshots_query {
+    #![allow(dead_code)]
+    use std::result::Result;
+    pub const OPERATION_NAME: &str = "PairsDaySnapshotsQuery";
+    pub const QUERY : & str = "query PairsDaySnapshotsQuery($pairs: [String!]!, $from: Int!, $skip: Int!) {\n  pairDaySnapshots(\n    first: 1000\n    skip: $skip\n    orderBy: date\n    orderDirection: asc\n    where: { pair_in: $pairs, date_gte: $from }\n  ) {\n    pair {\n      id\n    }\n    date\n    volumeUSD\n    liquidityUSD\n  }\n}" ;
+    use super::*;
+    use serde::{Deserialize, Serialize};
+    #[allow(dead_code)]
+    type Boolean = bool;
+    #[allow(dead_code)]
+    type Float = f64;
+    #[allow(dead_code)]
+    type Int = i64;
+    #[allow(dead_code)]
+    type ID = String;
+    type BigDecimal = super::BigDecimal;
+    #[derive(Serialize, Debug)]
+    pub struct Variables {
+        pub pairs: Vec<String>,
+        pub from: Int,
+        pub skip: Int,
+    }
+    impl Variables {}
+    #[derive(Deserialize, Debug)]
+    pub struct ResponseData {
+        #[serde(rename = "pairDaySnapshots")]
+        pub pair_day_snapshots: Vec<PairsDaySnapshotsQueryPairDaySnapshots>,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct PairsDaySnapshotsQueryPairDaySnapshots {
+        pub pair: PairsDaySnapshotsQueryPairDaySnapshotsPair,
+        pub date: Int,
+        #[serde(rename = "volumeUSD")]
+        pub volume_usd: BigDecimal,
+        #[serde(rename = "liquidityUSD")]
+        pub liquidity_usd: BigDecimal,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct PairsDaySnapshotsQueryPairDaySnapshotsPair {
+        pub id: ID,
+    }
+}
+impl graphql_client::GraphQLQuery for PairsDaySnapshotsQuery {
+    type Variables = pairs_day_snapshots_query::Variables;
+    type ResponseData = pairs_day_snapshots_query::ResponseData;
+    fn build_query(variables: Self::Variables) -> ::graphql_client::QueryBody<Self::Variables> {
+        graphql_client::QueryBody {
+            variables,
+            query: pairs_day_snapshots_query::QUERY,
+            operation_name: pairs_day_snapshots_query::OPERATION_NAME,
+        }
+    }
+}
@@ -0,0 +1,78 @@
+#![allow(clippy::all, warnings)]
+pub struct PairsStateQuery;
+type BigDecimal = String;
+type BigInt = String;
+type Bytes = String;
+pub mod pairs_state_query {
+    #![allow(dead_code)]
+    use std::result::Result;
+    pub const OPERATION_NAME: &str = "PairsStateQuery";
+    pub const QUERY : & str = "query PairsStateQuery($ids: [ID!]!, $block: Block_height) {\n  pairs(first: 1000, where: { id_in: $ids }, block: $block) {\n    id\n    name\n    source\n    swapFee\n    token0 {\n      id\n      symbol\n    }\n    token1 {\n      id\n      symbol\n    }\n    liquidity\n    liquidityUSD\n    volumeUSD\n    feesUSD\n  }\n}" ;
+    use super::*;
+    use serde::{Deserialize, Serialize};
+    #[allow(dead_code)]
+    type Boolean = bool;
+    #[allow(dead_code)]
+    type Float = f64;
+    #[allow(dead_code)]
+    type Int = i64;
+    #[allow(dead_code)]
+    type ID = String;
+    type BigDecimal = super::BigDecimal;
+    type BigInt = super::BigInt;
+    type Bytes = super::Bytes;
+    #[derive(Serialize, Debug)]
+    pub struct Block_height {
+        pub hash: Option<Bytes>,
+        pub number: Option<Int>,
+        pub number_gte: Option<Int>,
+    }
+    #[derive(Serialize, Debug)]
+    pub struct Variables {
+        pub ids: Vec<ID>,
+        pub block: Option<Block_height>,
+    }
+    impl Variables {}
+    #[derive(Deserialize, Debug)]
+    pub struct ResponseData {
+        pub pairs: Vec<PairsStateQueryPairs>,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct PairsStateQueryPairs {
+        pub id: ID,
+        pub name: String,
+        pub source: String,
+        #[serde(rename = "swapFee")]
+        pub swap_fee: BigInt,
+        pub token0: PairsStateQueryPairsToken0,
+        pub token1: PairsStateQueryPairsToken1,
+        pub liquidity: BigInt,
+        #[serde(rename = "liquidityUSD")]
+        pub liquidity_usd: BigDecimal,
+        #[serde(rename = "volumeUSD")]
+        pub volume_usd: BigDecimal,
+        #[serde(rename = "feesUSD")]
+        pub fees_usd: BigDecimal,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct PairsStateQueryPairsToken0 {
+        pub id: ID,
+        pub symbol: String,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct PairsStateQueryPairsToken1 {
+        pub id: ID,
+        pub symbol: String,
+    }
+}
+impl graphql_client::GraphQLQuery for PairsStateQuery {
+    type Variables = pairs_state_query::Variables;
+    type ResponseData = pairs_state_query::ResponseData;
+    fn build_query(variables: Self::Variables) -> ::graphql_client::QueryBody<Self::Variables> {
+        graphql_client::QueryBody {
+            variables,
+            query: pairs_state_query::QUERY,
+            operation_name: pairs_state_query::OPERATION_NAME,
+        }
+    }
+}
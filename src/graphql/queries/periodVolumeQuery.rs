@@ -1,11 +1,13 @@
 #![allow(clippy::all, warnings)]
 pub struct PeriodVolumeQuery;
 type BigDecimal = String;
+type BigInt = String;
+type Bytes = String;
 pub mod period_volume_query {
     #![allow(dead_code)]
     use std::result::Result;
     pub const OPERATION_NAME: &str = "PeriodVolumeQuery";
-    pub const QUERY : & str = "query PeriodVolumeQuery($tokenList: [String!], $block: Int!) {\n  oldPairsState: pairs(\n    first: 1000\n    orderBy: liquidityUSD\n    orderDirection: desc\n    where: { token0_in: $tokenList, token1_in: $tokenList, volumeUSD_gt: 0 }\n    block: {number: $block}\n  ) {\n    id\n    name\n    volumeUSD\n    feesUSD\n  }\n  newPairsState: pairs(\n    first: 1000\n    orderBy: liquidityUSD\n    orderDirection: desc\n    where: { token0_in: $tokenList, token1_in: $tokenList, createdAtBlock_lte: $block, volumeUSD_gt: 0 }\n  ) {\n    id\n    name\n    volumeUSD\n    feesUSD\n  }\n  newlyCreatedPairs: pairs(\n    first: 100\n    orderBy: volumeUSD\n    orderDirection: desc\n    where: { token0_in: $tokenList, token1_in: $tokenList, createdAtBlock_gt: $block, volumeUSD_gt: 0 }\n  ) {\n    id\n    name\n    volumeUSD\n    feesUSD\n  }\n}" ;
+    pub const QUERY : & str = "query PeriodVolumeQuery($tokenList: [String!], $block: Int!, $endBlock: Block_height) {\n  _meta(block: $endBlock) {\n    block {\n      number\n      timestamp\n    }\n  }\n  oldPairsState: pairs(\n    first: 1000\n    orderBy: liquidityUSD\n    orderDirection: desc\n    where: { token0_in: $tokenList, token1_in: $tokenList, volumeUSD_gt: 0 }\n    block: {number: $block}\n  ) {\n    id\n    name\n    volumeUSD\n    feesUSD\n  }\n  newPairsState: pairs(\n    first: 1000\n    orderBy: liquidityUSD\n    orderDirection: desc\n    where: { token0_in: $tokenList, token1_in: $tokenList, createdAtBlock_lte: $block, volumeUSD_gt: 0 }\n    block: $endBlock\n  ) {\n    ...PairVolume\n  }\n  newlyCreatedPairs: pairs(\n    first: 100\n    orderBy: volumeUSD\n    orderDirection: desc\n    where: { token0_in: $tokenList, token1_in: $tokenList, createdAtBlock_gt: $block, volumeUSD_gt: 0 }\n    block: $endBlock\n  ) {\n    ...PairVolume\n  }\n}\n\nfragment PairVolume on Pair {\n  id\n  name\n  source\n  swapFee\n  token0 {\n    id\n    symbol\n  }\n  token1 {\n    id\n    symbol\n  }\n  volumeUSD\n  feesUSD\n  liquidityUSD\n}" ;
     use super::*;
     use serde::{Deserialize, Serialize};
     #[allow(dead_code)]
@@ -17,15 +19,53 @@ pub mod period_volume_query {
     #[allow(dead_code)]
     type ID = String;
     type BigDecimal = super::BigDecimal;
+    type BigInt = super::BigInt;
+    type Bytes = super::Bytes;
+    #[derive(Serialize, Debug)]
+    pub struct Block_height {
+        pub hash: Option<Bytes>,
+        pub number: Option<Int>,
+        pub number_gte: Option<Int>,
+    }
     #[derive(Serialize, Debug)]
     pub struct Variables {
         #[serde(rename = "tokenList")]
         pub token_list: Option<Vec<String>>,
         pub block: Int,
+        #[serde(rename = "endBlock")]
+        pub end_block: Option<Block_height>,
     }
     impl Variables {}
     #[derive(Deserialize, Debug)]
+    pub struct PairVolume {
+        pub id: ID,
+        pub name: String,
+        pub source: String,
+        #[serde(rename = "swapFee")]
+        pub swap_fee: BigInt,
+        pub token0: PairVolumeToken0,
+        pub token1: PairVolumeToken1,
+        #[serde(rename = "volumeUSD")]
+        pub volume_usd: BigDecimal,
+        #[serde(rename = "feesUSD")]
+        pub fees_usd: BigDecimal,
+        #[serde(rename = "liquidityUSD")]
+        pub liquidity_usd: BigDecimal,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct PairVolumeToken0 {
+        pub id: ID,
+        pub symbol: String,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct PairVolumeToken1 {
+        pub id: ID,
+        pub symbol: String,
+    }
+    #[derive(Deserialize, Debug)]
     pub struct ResponseData {
+        #[serde(rename = "_meta")]
+        pub meta: Option<PeriodVolumeQueryMeta>,
         #[serde(rename = "oldPairsState")]
         pub old_pairs_state: Vec<PeriodVolumeQueryOldPairsState>,
         #[serde(rename = "newPairsState")]
@@ -34,25 +74,16 @@ pub mod period_volume_query {
         pub newly_created_pairs: Vec<PeriodVolumeQueryNewlyCreatedPairs>,
     }
     #[derive(Deserialize, Debug)]
-    pub struct PeriodVolumeQueryOldPairsState {
-        pub id: ID,
-        pub name: String,
-        #[serde(rename = "volumeUSD")]
-        pub volume_usd: BigDecimal,
-        #[serde(rename = "feesUSD")]
-        pub fees_usd: BigDecimal,
+    pub struct PeriodVolumeQueryMeta {
+        pub block: PeriodVolumeQueryMetaBlock,
     }
     #[derive(Deserialize, Debug)]
-    pub struct PeriodVolumeQueryNewPairsState {
-        pub id: ID,
-        pub name: String,
-        #[serde(rename = "volumeUSD")]
-        pub volume_usd: BigDecimal,
-        #[serde(rename = "feesUSD")]
-        pub fees_usd: BigDecimal,
+    pub struct PeriodVolumeQueryMetaBlock {
+        pub number: Int,
+        pub timestamp: Option<Int>,
     }
     #[derive(Deserialize, Debug)]
-    pub struct PeriodVolumeQueryNewlyCreatedPairs {
+    pub struct PeriodVolumeQueryOldPairsState {
         pub id: ID,
         pub name: String,
         #[serde(rename = "volumeUSD")]
@@ -60,6 +91,8 @@ pub mod period_volume_query {
         #[serde(rename = "feesUSD")]
         pub fees_usd: BigDecimal,
     }
+    pub type PeriodVolumeQueryNewPairsState = PairVolume;
+    pub type PeriodVolumeQueryNewlyCreatedPairs = PairVolume;
 }
 impl graphql_client::GraphQLQuery for PeriodVolumeQuery {
     type Variables = period_volume_query::Variables;
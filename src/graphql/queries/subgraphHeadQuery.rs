@@ -0,0 +1,45 @@
+#![allow(clippy::all, warnings)]
+pub struct SubgraphHeadQuery;
+pub mod subgraph_head_query {
+    #![allow(dead_code)]
+    use std::result::Result;
+    pub const OPERATION_NAME: &str = "SubgraphHeadQuery";
+    pub const QUERY: &str = "query SubgraphHeadQuery {\n  _meta {\n    block {\n      number\n      timestamp\n    }\n  }\n}";
+    use super::*;
+    use serde::{Deserialize, Serialize};
+    #[allow(dead_code)]
+    type Boolean = bool;
+    #[allow(dead_code)]
+    type Float = f64;
+    #[allow(dead_code)]
+    type Int = i64;
+    #[allow(dead_code)]
+    type ID = String;
+    #[derive(Serialize)]
+    pub struct Variables;
+    #[derive(Deserialize, Debug)]
+    pub struct ResponseData {
+        #[serde(rename = "_meta")]
+        pub meta: Option<SubgraphHeadQueryMeta>,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct SubgraphHeadQueryMeta {
+        pub block: SubgraphHeadQueryMetaBlock,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct SubgraphHeadQueryMetaBlock {
+        pub number: Int,
+        pub timestamp: Option<Int>,
+    }
+}
+impl graphql_client::GraphQLQuery for SubgraphHeadQuery {
+    type Variables = subgraph_head_query::Variables;
+    type ResponseData = subgraph_head_query::ResponseData;
+    fn build_query(variables: Self::Variables) -> ::graphql_client::QueryBody<Self::Variables> {
+        graphql_client::QueryBody {
+            variables,
+            query: subgraph_head_query::QUERY,
+            operation_name: subgraph_head_query::OPERATION_NAME,
+        }
+    }
+}
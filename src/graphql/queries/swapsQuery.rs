@@ -0,0 +1,66 @@
+#![allow(clippy::all, warnings)]
+pub struct SwapsQuery;
+type BigDecimal = String;
+type BigInt = String;
+pub mod swaps_query {
+    #![allow(dead_code)]
+    use std::result::Result;
+    pub const OPERATION_NAME: &str = "SwapsQuery";
+    pub const QUERY : & str = "query SwapsQuery($lastTimestamp: BigInt!, $lastId: ID!, $pairs: [String!]) {\n  swaps(\n    first: 1000\n    orderBy: timestamp\n    orderDirection: asc\n    where: {\n      and: [\n        { pair_in: $pairs }\n        { or: [{ timestamp_gt: $lastTimestamp }, { timestamp: $lastTimestamp, id_gt: $lastId }] }\n      ]\n    }\n  ) {\n    id\n    timestamp\n    pair {\n      id\n      name\n    }\n    sender\n    to\n    amountUSD\n    transaction {\n      id\n    }\n  }\n}" ;
+    use super::*;
+    use serde::{Deserialize, Serialize};
+    #[allow(dead_code)]
+    type Boolean = bool;
+    #[allow(dead_code)]
+    type Float = f64;
+    #[allow(dead_code)]
+    type Int = i64;
+    #[allow(dead_code)]
+    type ID = String;
+    type BigDecimal = super::BigDecimal;
+    type BigInt = super::BigInt;
+    #[derive(Serialize, Debug)]
+    pub struct Variables {
+        #[serde(rename = "lastTimestamp")]
+        pub last_timestamp: BigInt,
+        #[serde(rename = "lastId")]
+        pub last_id: ID,
+        pub pairs: Option<Vec<String>>,
+    }
+    impl Variables {}
+    #[derive(Deserialize, Debug)]
+    pub struct ResponseData {
+        pub swaps: Vec<SwapsQuerySwaps>,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct SwapsQuerySwaps {
+        pub id: ID,
+        pub timestamp: BigInt,
+        pub pair: SwapsQuerySwapsPair,
+        pub sender: String,
+        pub to: String,
+        #[serde(rename = "amountUSD")]
+        pub amount_usd: BigDecimal,
+        pub transaction: SwapsQuerySwapsTransaction,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct SwapsQuerySwapsPair {
+        pub id: ID,
+        pub name: String,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct SwapsQuerySwapsTransaction {
+        pub id: ID,
+    }
+}
+impl graphql_client::GraphQLQuery for SwapsQuery {
+    type Variables = swaps_query::Variables;
+    type ResponseData = swaps_query::ResponseData;
+    fn build_query(variables: Self::Variables) -> ::graphql_client::QueryBody<Self::Variables> {
+        graphql_client::QueryBody {
+            variables,
+            query: swaps_query::QUERY,
+            operation_name: swaps_query::OPERATION_NAME,
+        }
+    }
+}
@@ -0,0 +1,87 @@
+#![allow(clippy::all, warnings)]
+pub struct V3PoolsQuery;
+type BigDecimal = String;
+type BigInt = String;
+type Bytes = String;
+pub mod v3_pools_query {
+    #![allow(dead_code)]
+    use std::result::Result;
+    pub const OPERATION_NAME: &str = "V3PoolsQuery";
+    pub const QUERY : & str = "query V3PoolsQuery($tokenList: [String!], $block: Block_height) {\n  _meta(block: $block) {\n    block {\n      number\n      timestamp\n    }\n  }\n  pools(\n    first: 1000\n    orderBy: totalValueLockedUSD\n    orderDirection: desc\n    where: { token0_in: $tokenList, token1_in: $tokenList, volumeUSD_gt: 0 }\n    block: $block\n  ) {\n    id\n    token0 {\n      id\n      symbol\n    }\n    token1 {\n      id\n      symbol\n    }\n    feeTier\n    volumeUSD\n    feesUSD\n    totalValueLockedUSD\n  }\n}" ;
+    use super::*;
+    use serde::{Deserialize, Serialize};
+    #[allow(dead_code)]
+    type Boolean = bool;
+    #[allow(dead_code)]
+    type Float = f64;
+    #[allow(dead_code)]
+    type Int = i64;
+    #[allow(dead_code)]
+    type ID = String;
+    type BigDecimal = super::BigDecimal;
+    type BigInt = super::BigInt;
+    type Bytes = super::Bytes;
+    #[derive(Serialize, Debug)]
+    pub struct Block_height {
+        pub hash: Option<Bytes>,
+        pub number: Option<Int>,
+        pub number_gte: Option<Int>,
+    }
+    #[derive(Serialize, Debug)]
+    pub struct Variables {
+        #[serde(rename = "tokenList")]
+        pub token_list: Option<Vec<String>>,
+        pub block: Option<Block_height>,
+    }
+    impl Variables {}
+    #[derive(Deserialize, Debug)]
+    pub struct ResponseData {
+        #[serde(rename = "_meta")]
+        pub meta: Option<V3PoolsQueryMeta>,
+        pub pools: Vec<V3PoolsQueryPools>,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct V3PoolsQueryMeta {
+        pub block: V3PoolsQueryMetaBlock,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct V3PoolsQueryMetaBlock {
+        pub number: Int,
+        pub timestamp: Option<Int>,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct V3PoolsQueryPools {
+        pub id: ID,
+        pub token0: V3PoolsQueryPoolsToken0,
+        pub token1: V3PoolsQueryPoolsToken1,
+        #[serde(rename = "feeTier")]
+        pub fee_tier: BigInt,
+        #[serde(rename = "volumeUSD")]
+        pub volume_usd: BigDecimal,
+        #[serde(rename = "feesUSD")]
+        pub fees_usd: BigDecimal,
+        #[serde(rename = "totalValueLockedUSD")]
+        pub total_value_locked_usd: BigDecimal,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct V3PoolsQueryPoolsToken0 {
+        pub id: ID,
+        pub symbol: String,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct V3PoolsQueryPoolsToken1 {
+        pub id: ID,
+        pub symbol: String,
+    }
+}
+impl graphql_client::GraphQLQuery for V3PoolsQuery {
+    type Variables = v3_pools_query::Variables;
+    type ResponseData = v3_pools_query::ResponseData;
+    fn build_query(variables: Self::Variables) -> ::graphql_client::QueryBody<Self::Variables> {
+        graphql_client::QueryBody {
+            variables,
+            query: v3_pools_query::QUERY,
+            operation_name: v3_pools_query::OPERATION_NAME,
+        }
+    }
+}
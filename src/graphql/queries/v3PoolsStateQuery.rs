@@ -0,0 +1,57 @@
+#![allow(clippy::all, warnings)]
+pub struct V3PoolsStateQuery;
+type BigDecimal = String;
+type Bytes = String;
+pub mod v3_pools_state_query {
+    #![allow(dead_code)]
+    use std::result::Result;
+    pub const OPERATION_NAME: &str = "V3PoolsStateQuery";
+    pub const QUERY : & str = "query V3PoolsStateQuery($ids: [ID!]!, $block: Block_height) {\n  pools(first: 1000, where: { id_in: $ids }, block: $block) {\n    id\n    volumeUSD\n    feesUSD\n  }\n}" ;
+    use super::*;
+    use serde::{Deserialize, Serialize};
+    #[allow(dead_code)]
+    type Boolean = bool;
+    #[allow(dead_code)]
+    type Float = f64;
+    #[allow(dead_code)]
+    type Int = i64;
+    #[allow(dead_code)]
+    type ID = String;
+    type BigDecimal = super::BigDecimal;
+    type Bytes = super::Bytes;
+    #[derive(Serialize, Debug)]
+    pub struct Block_height {
+        pub hash: Option<Bytes>,
+        pub number: Option<Int>,
+        pub number_gte: Option<Int>,
+    }
+    #[derive(Serialize, Debug)]
+    pub struct Variables {
+        pub ids: Vec<ID>,
+        pub block: Option<Block_height>,
+    }
+    impl Variables {}
+    #[derive(Deserialize, Debug)]
+    pub struct ResponseData {
+        pub pools: Vec<V3PoolsStateQueryPools>,
+    }
+    #[derive(Deserialize, Debug)]
+    pub struct V3PoolsStateQueryPools {
+        pub id: ID,
+        #[serde(rename = "volumeUSD")]
+        pub volume_usd: BigDecimal,
+        #[serde(rename = "feesUSD")]
+        pub fees_usd: BigDecimal,
+    }
+}
+impl graphql_client::GraphQLQuery for V3PoolsStateQuery {
+    type Variables = v3_pools_state_query::Variables;
+    type ResponseData = v3_pools_state_query::ResponseData;
+    fn build_query(variables: Self::Variables) -> ::graphql_client::QueryBody<Self::Variables> {
+        graphql_client::QueryBody {
+            variables,
+            query: v3_pools_state_query::QUERY,
+            operation_name: v3_pools_state_query::OPERATION_NAME,
+        }
+    }
+}
@@ -0,0 +1,93 @@
+use crate::{
+    helpers::block::block_timestamp::{Block, BlockQueryError},
+    network, rpc,
+};
+use serde::Deserialize;
+
+//each step costs one eth_getBlockByNumber call, 64 is way more than needed on any chain
+const MAX_SEARCH_STEPS: u32 = 64;
+
+#[derive(Deserialize, Debug)]
+struct RpcBlock {
+    number: String,
+    timestamp: String,
+}
+
+fn parse_hex(value: &str) -> Result<u64, BlockQueryError> {
+    match u64::from_str_radix(value.trim_start_matches("0x"), 16) {
+        Ok(value) => Ok(value),
+        Err(error) => Err(BlockQueryError::ParsingError(error)),
+    }
+}
+
+fn rpc_url(chain: &str) -> Result<&'static str, BlockQueryError> {
+    match network::NETWORKS.get(chain) {
+        Some(network) => Ok(network.rpc),
+        None => Err(BlockQueryError::NoRpcUrl(chain.to_string())),
+    }
+}
+
+fn query_rpc_block(rpc_url: &str, tag: String) -> Result<Block, BlockQueryError> {
+    let block: Option<RpcBlock> =
+        match rpc::query_rpc(rpc_url, "eth_getBlockByNumber", (tag, false)) {
+            Ok(block) => block,
+            Err(error) => return Err(BlockQueryError::RequestError(error.to_string())),
+        };
+
+    match block {
+        Some(block) => Ok(Block {
+            number: parse_hex(&block.number)?,
+            timestamp: parse_hex(&block.timestamp)?,
+        }),
+        None => Err(BlockQueryError::EmptyResponse),
+    }
+}
+
+pub fn query_block_by_number(chain: &str, number: u64) -> Result<Block, BlockQueryError> {
+    query_rpc_block(rpc_url(chain)?, format!("{number:#x}"))
+}
+
+/// Find the last block mined strictly before `timestamp` (same semantic as the block subgraphs).
+///
+/// Interpolation search over `eth_getBlockByNumber`, alternating with plain bisection so that
+/// chains with irregular block times still converge in a bounded number of calls.
+pub fn search_block_by_timestamp(chain: &str, timestamp: u64) -> Result<Block, BlockQueryError> {
+    let rpc_url = rpc_url(chain)?;
+
+    let mut high = query_rpc_block(rpc_url, "latest".to_string())?;
+    if high.timestamp < timestamp {
+        return Ok(high);
+    }
+    //genesis timestamp is often 0 on sidechains, start at block 1
+    let mut low = query_rpc_block(rpc_url, format!("{:#x}", 1))?;
+    if low.timestamp >= timestamp {
+        return Err(BlockQueryError::NotFound(chain.to_string(), timestamp));
+    }
+
+    for step in 0..MAX_SEARCH_STEPS {
+        if high.number - low.number <= 1 {
+            return Ok(low);
+        }
+
+        let guess = if step % 2 == 0 && high.timestamp > low.timestamp {
+            let progress =
+                (timestamp - low.timestamp) as f64 / (high.timestamp - low.timestamp) as f64;
+            low.number + (progress * (high.number - low.number) as f64) as u64
+        } else {
+            low.number + (high.number - low.number) / 2
+        };
+        let guess = guess.clamp(low.number + 1, high.number - 1);
+
+        let block = query_rpc_block(rpc_url, format!("{guess:#x}"))?;
+        if block.timestamp < timestamp {
+            low = block;
+        } else {
+            high = block;
+        }
+    }
+
+    Err(BlockQueryError::SearchExhausted(
+        chain.to_string(),
+        timestamp,
+    ))
+}
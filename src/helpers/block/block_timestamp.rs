@@ -1,29 +1,65 @@
 use crate::{
     graphql::queries::blockByTimestamp::{block_by_timestamp, BlockByTimestamp},
+    helpers::block::block_rpc::search_block_by_timestamp,
     network, subgraph,
 };
 use graphql_client::{GraphQLQuery, Response};
 use std::{
+    collections::HashMap,
     num::ParseIntError,
+    thread::{self, JoinHandle},
     time::{SystemTime, UNIX_EPOCH},
 };
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Block {
+    pub number: u64,
+    pub timestamp: u64,
+}
+
 #[derive(Debug)]
+#[allow(dead_code)] //only read through Debug when printing errors
 pub enum BlockQueryError {
     NoSubgraphUrl(String),
+    NoRpcUrl(String),
     RequestError(String),
     EmptyResponse,
     ParsingError(ParseIntError),
+    NotFound(String, u64),
+    SearchExhausted(String, u64),
+    UnknownBlockSource(String),
+}
+
+/// Where block numbers are resolved from.
+/// Subgraph falls back to the RPC when the chain has no block subgraph or the query fails,
+/// Rpc cross-checks its result against the block subgraph when one exists.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BlockSource {
+    Subgraph,
+    Rpc,
+}
+
+impl BlockSource {
+    pub fn parse(source: &str) -> Result<BlockSource, BlockQueryError> {
+        match source {
+            "subgraph" => Ok(BlockSource::Subgraph),
+            "rpc" => Ok(BlockSource::Rpc),
+            _ => Err(BlockQueryError::UnknownBlockSource(source.to_string())),
+        }
+    }
 }
 
-pub fn query_block_timestamp(chain: &str, days: u32) -> Result<i64, BlockQueryError> {
-    let time: u64 = SystemTime::now()
+pub fn now() -> u64 {
+    SystemTime::now()
         .duration_since(UNIX_EPOCH)
         .unwrap() //unlikely to panic
-        .as_secs();
-
-    let timestamp = time - u64::from(86_400 * days);
+        .as_secs()
+}
 
+pub fn query_subgraph_block_by_timestamp(
+    chain: &str,
+    timestamp: u64,
+) -> Result<Block, BlockQueryError> {
     let block_request_body =
         BlockByTimestamp::build_query(block_by_timestamp::Variables { timestamp });
 
@@ -38,13 +74,102 @@ pub fn query_block_timestamp(chain: &str, days: u32) -> Result<i64, BlockQueryEr
             Err(error) => return Err(BlockQueryError::RequestError(error.to_string())),
         };
 
-    let block_string = match block_res.data {
-        Some(block) => block.blocks[0].number.clone(),
+    let block = match block_res.data {
+        Some(data) => match data.blocks.into_iter().next() {
+            Some(block) => block,
+            None => return Err(BlockQueryError::EmptyResponse),
+        },
         None => return Err(BlockQueryError::EmptyResponse),
     };
 
-    match block_string.parse() {
-        Ok(block) => Ok(block),
+    let number = match block.number.parse() {
+        Ok(number) => number,
+        Err(error) => return Err(BlockQueryError::ParsingError(error)),
+    };
+    match block.timestamp.parse() {
+        Ok(timestamp) => Ok(Block { number, timestamp }),
         Err(error) => Err(BlockQueryError::ParsingError(error)),
     }
 }
+
+fn cross_check(chain: &str, timestamp: u64, rpc_block: &Block) {
+    if !network::BLOCK_SUBGRAPH.contains_key(chain) {
+        return;
+    }
+    match query_subgraph_block_by_timestamp(chain, timestamp) {
+        Ok(subgraph_block) if subgraph_block.number != rpc_block.number => eprintln!(
+            "Warning: {chain} block mismatch at {timestamp}, rpc: {} subgraph: {} ({} blocks apart).",
+            rpc_block.number,
+            subgraph_block.number,
+            rpc_block.number.abs_diff(subgraph_block.number)
+        ),
+        Ok(_) => {}
+        Err(error) => eprintln!("Warning: could not cross-check {chain} block with subgraph: {:?}", error),
+    }
+}
+
+/// Last block mined before `timestamp`.
+pub fn query_block_at(
+    chain: &str,
+    timestamp: u64,
+    source: BlockSource,
+) -> Result<Block, BlockQueryError> {
+    match source {
+        BlockSource::Subgraph => match query_subgraph_block_by_timestamp(chain, timestamp) {
+            Ok(block) => Ok(block),
+            Err(error) => {
+                if !matches!(error, BlockQueryError::NoSubgraphUrl(_)) {
+                    eprintln!(
+                        "Block subgraph failed for {chain}, falling back to rpc: {:?}",
+                        error
+                    );
+                }
+                search_block_by_timestamp(chain, timestamp)
+            }
+        },
+        BlockSource::Rpc => {
+            let block = search_block_by_timestamp(chain, timestamp)?;
+            cross_check(chain, timestamp, &block);
+            Ok(block)
+        }
+    }
+}
+
+pub fn query_block_timestamp(
+    chain: &str,
+    days: u32,
+    source: BlockSource,
+) -> Result<i64, BlockQueryError> {
+    let timestamp = now() - u64::from(86_400 * days);
+    let block = query_block_at(chain, timestamp, source)?;
+    Ok(block.number as i64)
+}
+
+type ChainBlockHandle = JoinHandle<Result<(String, Block), (String, BlockQueryError)>>;
+
+pub fn query_multichain_block_at(
+    chains: Vec<String>,
+    timestamp: u64,
+    source: BlockSource,
+) -> HashMap<String, Block> {
+    let mut handles: Vec<ChainBlockHandle> = vec![];
+    for chain in chains {
+        let handle = thread::spawn(move || match query_block_at(&chain, timestamp, source) {
+            Ok(block) => Ok((chain, block)),
+            Err(error) => Err((chain, error)),
+        });
+        handles.push(handle);
+    }
+
+    let mut result = HashMap::new();
+    for handle in handles {
+        match handle.join().unwrap() {
+            Ok(block) => result.insert(block.0, block.1),
+            Err((chain, error)) => {
+                eprintln!("Error while querying {chain} block: {:#?}", error);
+                continue;
+            }
+        };
+    }
+    result
+}
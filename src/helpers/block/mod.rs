@@ -1 +1,2 @@
+pub mod block_rpc;
 pub mod block_timestamp;
@@ -0,0 +1,24 @@
+use std::{error::Error, str::FromStr};
+
+use ethers::{
+    abi::{decode, encode, ParamType, Token},
+    types::{Address, U256},
+    utils::id,
+};
+
+use crate::{network::NETWORKS, rpc};
+
+pub fn query_balance_of(chain: &str, token: &str, owner: &str) -> Result<U256, Box<dyn Error>> {
+    let rpc_url = match NETWORKS.get(chain) {
+        Some(network) => network.rpc,
+        None => return Err(format!("no rpc for {chain}").into()),
+    };
+
+    let mut data = id("balanceOf(address)").to_vec();
+    data.extend(encode(&[Token::Address(Address::from_str(owner)?)]));
+    let result = rpc::eth_call(rpc_url, token, data, None)?;
+    match decode(&[ParamType::Uint(256)], &result)?.pop() {
+        Some(Token::Uint(balance)) => Ok(balance),
+        _ => Err(format!("unexpected balanceOf result {result}").into()),
+    }
+}
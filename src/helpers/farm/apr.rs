@@ -0,0 +1,133 @@
+use std::{
+    collections::HashMap,
+    thread::{self, JoinHandle},
+};
+
+use crate::helpers::{
+    block::block_timestamp::{now, query_block_timestamp, BlockQueryError},
+    erc20::query_balance_of,
+    farm::pools_with_sushi::{query_pools_with_sushi, Minichef, PoolApr, PoolsWithSushiQueryError},
+    fees::fee_model::FeeModels,
+    volume::{
+        pairs_state::{query_pairs_state, PairState, PairsStateQueryError},
+        period_volume::Period,
+    },
+};
+
+#[derive(Debug)]
+#[allow(dead_code)] //only read through Debug when printing errors
+pub enum FarmAprError {
+    Farms(PoolsWithSushiQueryError),
+    Block(String, BlockQueryError),
+    Pairs(PairsStateQueryError),
+    Balance(String, String),
+}
+
+/// Chef farms with their APR attached and the current state of their pairs.
+#[derive(Debug)]
+pub struct FarmsApr {
+    pub chain: String,
+    pub minichef: Minichef,
+    pub pairs: HashMap<String, PairState>,
+}
+
+/// Price the LP staked in every farm of a chain from its pair liquidity and attach the SUSHI
+/// reward APR and the LP fee APR (fees over the period, treasury share removed) to each pool.
+/// Staked LP comes from the chef subgraph, or from balanceOf calls if onchain is set.
+pub fn query_farms_apr(
+    chain: String,
+    period: &Period,
+    sushi_price: f64,
+    fee_models: &FeeModels,
+    onchain: bool,
+) -> Result<FarmsApr, FarmAprError> {
+    let mut minichef = match query_pools_with_sushi(chain.clone()) {
+        Ok(minichef) => minichef,
+        Err(error) => return Err(FarmAprError::Farms(error)),
+    };
+    let start_block = match query_block_timestamp(&chain, period.days, period.block_source) {
+        Ok(block) => block as u64,
+        Err(error) => return Err(FarmAprError::Block(chain, error)),
+    };
+
+    let ids: Vec<String> = minichef.pools.keys().cloned().collect();
+    let start_states = match query_pairs_state(&chain, &ids, Some(start_block)) {
+        Ok(states) => states,
+        Err(error) => return Err(FarmAprError::Pairs(error)),
+    };
+    let end_states = match query_pairs_state(&chain, &ids, None) {
+        Ok(states) => states,
+        Err(error) => return Err(FarmAprError::Pairs(error)),
+    };
+
+    let timestamp = now();
+    for pool in minichef.pools.values_mut() {
+        let end = match end_states.get(&pool.id) {
+            Some(end) => end,
+            None => continue, //not a pair, can't be priced
+        };
+        if onchain {
+            pool.slp_balance = match query_balance_of(&chain, &pool.id, &pool.chef) {
+                Ok(balance) => balance.to_string().parse().unwrap(), //in wei, as the subgraph balances
+                Err(error) => return Err(FarmAprError::Balance(chain, error.to_string())),
+            };
+        }
+        if end.total_supply == 0.0 || end.liquidity_usd == 0.0 {
+            continue;
+        }
+
+        let staked_tvl_usd = pool.slp_balance / end.total_supply * end.liquidity_usd;
+        let pair = end.period_pair(start_states.get(&pool.id));
+        let lp_share = 1.0
+            - fee_models
+                .model_for(&chain, &pair, timestamp)
+                .share_of(&pair);
+        let fees_per_day = pair.fees_usd * lp_share / period.days as f64;
+
+        pool.apr = Some(PoolApr {
+            staked_tvl_usd,
+            reward_apr: match staked_tvl_usd {
+                tvl if tvl > 0.0 => pool.sushi_per_day * sushi_price * 365.0 / tvl,
+                _ => 0.0,
+            },
+            fee_apr: fees_per_day * 365.0 / end.liquidity_usd,
+        });
+    }
+
+    Ok(FarmsApr {
+        chain,
+        minichef,
+        pairs: end_states,
+    })
+}
+
+type ChainAprHandle = JoinHandle<Result<FarmsApr, FarmAprError>>;
+
+pub fn query_farms_apr_multichain(
+    chains: Vec<String>,
+    period: Period,
+    sushi_price: f64,
+    fee_models: &FeeModels,
+    onchain: bool,
+) -> Vec<FarmsApr> {
+    let mut handles: Vec<ChainAprHandle> = vec![];
+    for chain in chains {
+        let fee_models = fee_models.clone();
+        let period = period.clone();
+        handles.push(thread::spawn(move || {
+            query_farms_apr(chain, &period, sushi_price, &fee_models, onchain)
+        }));
+    }
+
+    let mut result: Vec<FarmsApr> = vec![];
+    for handle in handles {
+        match handle.join().unwrap() {
+            Ok(farms) => result.push(farms),
+            Err(error) => {
+                eprintln!("Error while querying farms apr: {:#?}", error);
+                continue;
+            }
+        };
+    }
+    result
+}
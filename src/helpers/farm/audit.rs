@@ -0,0 +1,185 @@
+use std::{
+    collections::HashMap,
+    thread::{self, JoinHandle},
+};
+
+use crate::helpers::{
+    block::block_timestamp::{now, query_block_timestamp, BlockQueryError, BlockSource},
+    farm::pools_with_sushi::{query_pools_with_sushi, PoolsWithSushiQueryError},
+    fees::fee_model::FeeModels,
+    volume::pairs_state::{query_pairs_state, query_pairs_state_or_unparsed, PairsStateQueryError},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AuditFlag {
+    NotAPair,          //staked token isn't a pair of the exchange
+    UnparsablePair,    //pair returned by the subgraph but its state can't be parsed
+    NoVolume,          //volume per day below the threshold
+    LowFees,           //treasury fees below the threshold fraction of emissions
+    LiquidityCollapse, //liquidity dropped more than the threshold over the period
+}
+
+/// Limits a farm is flagged against.
+#[derive(Debug, Clone, Copy)]
+pub struct AuditThresholds {
+    pub min_volume_per_day: f64,
+    pub min_fee_ratio: f64,
+    pub max_liquidity_drop: f64,
+}
+
+#[derive(Debug, Clone)]
+pub struct FarmAudit {
+    pub chain: String,
+    pub pid: u32,
+    pub pair: String,
+    pub name: Option<String>,
+    pub sushi_per_day: f64,
+    pub volume_usd: f64,
+    pub fees_usd: f64, //treasury share only
+    pub spent_usd: f64,
+    pub start_liquidity_usd: Option<f64>,
+    pub end_liquidity_usd: Option<f64>,
+    pub flags: Vec<AuditFlag>,
+}
+
+#[derive(Debug)]
+#[allow(dead_code)] //only read through Debug when printing errors
+pub enum FarmAuditError {
+    Farms(PoolsWithSushiQueryError),
+    Block(String, BlockQueryError),
+    Pairs(PairsStateQueryError),
+}
+
+/// Join a chain farms with their pairs state at the period start and now and flag the ones
+/// not worth their emissions.
+pub fn audit_farms(
+    chain: String,
+    days: u32,
+    block_source: BlockSource,
+    thresholds: AuditThresholds,
+    fee_models: &FeeModels,
+    sushi_price: f64,
+) -> Result<Vec<FarmAudit>, FarmAuditError> {
+    let minichef = match query_pools_with_sushi(chain.clone()) {
+        Ok(minichef) => minichef,
+        Err(error) => return Err(FarmAuditError::Farms(error)),
+    };
+    let start_block = match query_block_timestamp(&chain, days, block_source) {
+        Ok(block) => block as u64,
+        Err(error) => return Err(FarmAuditError::Block(chain, error)),
+    };
+
+    let ids: Vec<String> = minichef
+        .pools
+        .values()
+        .filter(|pool| pool.alloc_point > 0)
+        .map(|pool| pool.id.clone())
+        .collect();
+    let start_states = match query_pairs_state(&chain, &ids, Some(start_block)) {
+        Ok(states) => states,
+        Err(error) => return Err(FarmAuditError::Pairs(error)),
+    };
+    let end_states = match query_pairs_state_or_unparsed(&chain, &ids, None) {
+        Ok(states) => states,
+        Err(error) => return Err(FarmAuditError::Pairs(error)),
+    };
+
+    let timestamp = now();
+    let mut audits: Vec<FarmAudit> = vec![];
+    for pool in minichef.pools.values().filter(|pool| pool.alloc_point > 0) {
+        let spent_usd = pool.sushi_per_day * days as f64 * sushi_price;
+        let mut audit = FarmAudit {
+            chain: chain.clone(),
+            pid: pool.pid,
+            pair: pool.id.clone(),
+            name: None,
+            sushi_per_day: pool.sushi_per_day,
+            volume_usd: 0.0,
+            fees_usd: 0.0,
+            spent_usd,
+            start_liquidity_usd: None,
+            end_liquidity_usd: None,
+            flags: vec![],
+        };
+
+        let end = match end_states.get(&pool.id) {
+            Some(Some(end)) => end,
+            Some(None) => {
+                audit.flags.push(AuditFlag::UnparsablePair);
+                audits.push(audit);
+                continue;
+            }
+            None => {
+                audit.flags.push(AuditFlag::NotAPair);
+                audits.push(audit);
+                continue;
+            }
+        };
+        let start = start_states.get(&pool.id);
+        let pair = end.period_pair(start);
+        let fee_model = fee_models.model_for(&chain, &pair, timestamp);
+
+        audit.name = Some(pair.name.clone());
+        audit.volume_usd = pair.volume_usd;
+        audit.fees_usd = pair.fees_usd * fee_model.share_of(&pair);
+        audit.start_liquidity_usd = start.map(|start| start.liquidity_usd);
+        audit.end_liquidity_usd = Some(end.liquidity_usd);
+
+        if pair.volume_usd / (days as f64) < thresholds.min_volume_per_day {
+            audit.flags.push(AuditFlag::NoVolume);
+        }
+        if audit.fees_usd < spent_usd * thresholds.min_fee_ratio {
+            audit.flags.push(AuditFlag::LowFees);
+        }
+        if let Some(start) = start {
+            if end.liquidity_usd < start.liquidity_usd * (1.0 - thresholds.max_liquidity_drop) {
+                audit.flags.push(AuditFlag::LiquidityCollapse);
+            }
+        }
+        audits.push(audit);
+    }
+
+    Ok(audits)
+}
+
+type ChainAuditHandle = JoinHandle<Result<(String, Vec<FarmAudit>), FarmAuditError>>;
+
+pub fn audit_farms_multichain(
+    chains: Vec<String>,
+    days: u32,
+    block_source: BlockSource,
+    thresholds: AuditThresholds,
+    fee_models: &FeeModels,
+    sushi_price: f64,
+) -> HashMap<String, Vec<FarmAudit>> {
+    let mut handles: Vec<ChainAuditHandle> = vec![];
+    for chain in chains {
+        let fee_models = fee_models.clone();
+        let handle = thread::spawn(move || {
+            match audit_farms(
+                chain.clone(),
+                days,
+                block_source,
+                thresholds,
+                &fee_models,
+                sushi_price,
+            ) {
+                Ok(audits) => Ok((chain, audits)),
+                Err(error) => Err(error),
+            }
+        });
+        handles.push(handle);
+    }
+
+    let mut result = HashMap::new();
+    for handle in handles {
+        match handle.join().unwrap() {
+            Ok(audits) => result.insert(audits.0, audits.1),
+            Err(error) => {
+                eprintln!("Error while auditing farms: {:#?}", error);
+                continue;
+            }
+        };
+    }
+    result
+}
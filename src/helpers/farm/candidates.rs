@@ -0,0 +1,147 @@
+use std::thread::{self, JoinHandle};
+
+use crate::helpers::{
+    block::block_timestamp::{now, query_block_timestamp, BlockQueryError},
+    farm::pools_with_sushi::{query_pools_with_sushi, PoolsWithSushiQueryError},
+    fees::fee_model::FeeModels,
+    volume::{
+        pairs_state::{query_pairs_state, PairsStateQueryError},
+        period_volume::{query_period_volume, BlockBoundaries, Period, PeriodVolumeQueryError},
+    },
+};
+
+/// Pair earning fees without any farm.
+#[derive(Debug, Clone)]
+pub struct Candidate {
+    pub chain: String,
+    pub pair: String,
+    pub name: String,
+    pub volume_usd: f64,
+    pub fees_usd: f64, //treasury share only
+    pub previous_volume_usd: Option<f64>,
+    pub liquidity_usd: Option<f64>,
+}
+
+impl Candidate {
+    //volume change against the previous period of same length, None if the pair is new
+    pub fn volume_growth(&self) -> Option<f64> {
+        match self.previous_volume_usd {
+            Some(previous) if previous > 0.0 => Some(self.volume_usd / previous - 1.0),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug)]
+#[allow(dead_code)] //only read through Debug when printing errors
+pub enum CandidatesError {
+    Farms(PoolsWithSushiQueryError),
+    Block(String, BlockQueryError),
+    Volume(PeriodVolumeQueryError),
+    Pairs(PairsStateQueryError),
+}
+
+/// Unfarmed pairs of a chain whose treasury fees over the period reach min_fees.
+pub fn find_candidates(
+    chain: String,
+    period: &Period,
+    fee_models: &FeeModels,
+    min_fees: f64,
+) -> Result<Vec<Candidate>, CandidatesError> {
+    let minichef = match query_pools_with_sushi(chain.clone()) {
+        Ok(minichef) => minichef,
+        Err(error) => return Err(CandidatesError::Farms(error)),
+    };
+    let volume = match query_period_volume(chain.clone(), period, BlockBoundaries::default()) {
+        Ok(volume) => volume,
+        Err(error) => return Err(CandidatesError::Volume(error)),
+    };
+
+    let timestamp = volume.end_timestamp.unwrap_or_else(now);
+    let mut candidates: Vec<Candidate> = volume
+        .pairs
+        .values()
+        .filter(|pair| {
+            minichef
+                .pools
+                .get(&pair.id)
+                .is_none_or(|pool| pool.alloc_point == 0)
+        })
+        .map(|pair| Candidate {
+            chain: chain.clone(),
+            pair: pair.id.clone(),
+            name: pair.name.clone(),
+            volume_usd: pair.volume_usd,
+            fees_usd: pair.fees_usd * fee_models.model_for(&chain, pair, timestamp).share_of(pair),
+            previous_volume_usd: None,
+            liquidity_usd: None,
+        })
+        .filter(|candidate| candidate.fees_usd >= min_fees)
+        .collect();
+    if candidates.is_empty() {
+        return Ok(candidates);
+    }
+
+    //same length period right before the current one
+    let previous_start = match query_block_timestamp(&chain, period.days * 2, period.block_source) {
+        Ok(block) => block as u64,
+        Err(error) => return Err(CandidatesError::Block(chain, error)),
+    };
+    let previous_boundaries = BlockBoundaries {
+        start: Some(previous_start),
+        end: Some(volume.start_block),
+    };
+    let previous = match query_period_volume(chain.clone(), period, previous_boundaries) {
+        Ok(previous) => previous,
+        Err(error) => return Err(CandidatesError::Volume(error)),
+    };
+
+    let ids: Vec<String> = candidates
+        .iter()
+        .map(|candidate| candidate.pair.clone())
+        .collect();
+    let states = match query_pairs_state(&chain, &ids, Some(volume.end_block)) {
+        Ok(states) => states,
+        Err(error) => return Err(CandidatesError::Pairs(error)),
+    };
+
+    for candidate in candidates.iter_mut() {
+        candidate.previous_volume_usd = previous
+            .pairs
+            .get(&candidate.pair)
+            .map(|pair| pair.volume_usd);
+        candidate.liquidity_usd = states.get(&candidate.pair).map(|state| state.liquidity_usd);
+    }
+
+    Ok(candidates)
+}
+
+type ChainCandidatesHandle = JoinHandle<Result<Vec<Candidate>, CandidatesError>>;
+
+pub fn find_candidates_multichain(
+    chains: Vec<String>,
+    period: Period,
+    fee_models: &FeeModels,
+    min_fees: f64,
+) -> Vec<Candidate> {
+    let mut handles: Vec<ChainCandidatesHandle> = vec![];
+    for chain in chains {
+        let fee_models = fee_models.clone();
+        let period = period.clone();
+        handles.push(thread::spawn(move || {
+            find_candidates(chain, &period, &fee_models, min_fees)
+        }));
+    }
+
+    let mut result: Vec<Candidate> = vec![];
+    for handle in handles {
+        match handle.join().unwrap() {
+            Ok(candidates) => result.extend(candidates),
+            Err(error) => {
+                eprintln!("Error while looking for farm candidates: {:#?}", error);
+                continue;
+            }
+        };
+    }
+    result
+}
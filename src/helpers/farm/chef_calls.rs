@@ -0,0 +1,190 @@
+use ethers::{
+    abi::{encode, Token},
+    types::{Address, Bytes, U256},
+    utils::id,
+};
+use serde::Serialize;
+
+use crate::network::{MASTERCHEFV2_ADDRESS, MASTERCHEF_ADDRESS, MINICHEF_ADDRESS, NETWORKS};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChefKind {
+    MasterChef,
+    MasterChefV2,
+    MiniChef,
+}
+
+#[derive(Debug, Clone)]
+pub struct Chef {
+    pub kind: ChefKind,
+    pub chain: String,
+    pub address: String,
+}
+
+impl Chef {
+    /// Chef from a network name, or `masterchef`/`masterchefv2` for the mainnet chefs.
+    pub fn from_key(key: &str) -> Option<Chef> {
+        let (kind, chain, address) = match key {
+            "masterchef" | "ethereum" => (ChefKind::MasterChef, "ethereum", MASTERCHEF_ADDRESS),
+            "masterchefv2" => (ChefKind::MasterChefV2, "ethereum", MASTERCHEFV2_ADDRESS),
+            chain => (ChefKind::MiniChef, chain, *MINICHEF_ADDRESS.get(chain)?),
+        };
+        Some(Chef {
+            kind,
+            chain: chain.to_string(),
+            address: address.to_string(),
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ChefCall {
+    pub to: String,
+    pub description: String,
+    pub data: Bytes,
+}
+
+fn calldata(signature: &str, tokens: &[Token]) -> Bytes {
+    let mut data = id(signature).to_vec();
+    data.extend(encode(tokens));
+    data.into()
+}
+
+/// `set` call, the rewarder is only overwritten when one is given. None for a rewarder on
+/// MasterChef v1, which has none.
+pub fn encode_set(
+    chef: &Chef,
+    pid: u32,
+    alloc_point: u32,
+    rewarder: Option<Address>,
+) -> Option<ChefCall> {
+    let data = match chef.kind {
+        ChefKind::MasterChef if rewarder.is_some() => return None,
+        ChefKind::MasterChef => calldata(
+            "set(uint256,uint256,bool)",
+            &[
+                Token::Uint(U256::from(pid)),
+                Token::Uint(U256::from(alloc_point)),
+                Token::Bool(false), //pools are mass updated first
+            ],
+        ),
+        ChefKind::MasterChefV2 | ChefKind::MiniChef => calldata(
+            "set(uint256,uint256,address,bool)",
+            &[
+                Token::Uint(U256::from(pid)),
+                Token::Uint(U256::from(alloc_point)),
+                Token::Address(rewarder.unwrap_or_default()),
+                Token::Bool(rewarder.is_some()),
+            ],
+        ),
+    };
+    let description = match rewarder {
+        Some(rewarder) => format!("set({pid}, {alloc_point}, {rewarder:?}, true)"),
+        None => format!("set({pid}, {alloc_point})"),
+    };
+    Some(ChefCall {
+        to: chef.address.clone(),
+        description,
+        data,
+    })
+}
+
+/// `setSushiPerSecond` call, MiniChef only as the mainnet chefs emission is fixed.
+pub fn encode_set_sushi_per_second(chef: &Chef, sushi_per_second: U256) -> Option<ChefCall> {
+    if chef.kind != ChefKind::MiniChef {
+        return None;
+    }
+    Some(ChefCall {
+        to: chef.address.clone(),
+        description: format!("setSushiPerSecond({sushi_per_second})"),
+        data: calldata(
+            "setSushiPerSecond(uint256)",
+            &[Token::Uint(sushi_per_second)],
+        ),
+    })
+}
+
+pub fn encode_mass_update_pools(chef: &Chef, pids: &[u32]) -> ChefCall {
+    match chef.kind {
+        ChefKind::MasterChef => ChefCall {
+            to: chef.address.clone(),
+            description: "massUpdatePools()".to_string(),
+            data: calldata("massUpdatePools()", &[]),
+        },
+        ChefKind::MasterChefV2 | ChefKind::MiniChef => ChefCall {
+            to: chef.address.clone(),
+            description: format!("massUpdatePools({pids:?})"),
+            data: calldata(
+                "massUpdatePools(uint256[])",
+                &[Token::Array(
+                    pids.iter()
+                        .map(|pid| Token::Uint(U256::from(*pid)))
+                        .collect(),
+                )],
+            ),
+        },
+    }
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct SafeBatchMeta {
+    pub name: String,
+    pub description: String,
+    pub tx_builder_version: String,
+    pub created_from_safe_address: String,
+    pub created_from_owner_address: String,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct SafeTransaction {
+    pub to: String,
+    pub value: String,
+    pub data: String,
+    pub contract_method: Option<String>,
+    pub contract_inputs_values: Option<String>,
+}
+
+/// Gnosis Safe transaction builder batch file.
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct SafeBatch {
+    pub version: String,
+    pub chain_id: String,
+    pub created_at: u64,
+    pub meta: SafeBatchMeta,
+    pub transactions: Vec<SafeTransaction>,
+}
+
+impl SafeBatch {
+    pub fn new(chef: &Chef, calls: &[ChefCall], created_at: u64) -> Option<SafeBatch> {
+        let chain_id = NETWORKS.get(chef.chain.as_str())?.chain_id;
+        Some(SafeBatch {
+            version: "1.0".to_string(),
+            chain_id: chain_id.to_string(),
+            created_at: created_at * 1_000, //in ms
+            meta: SafeBatchMeta {
+                name: format!("{} alloc points", chef.chain),
+                description: calls
+                    .iter()
+                    .map(|call| call.description.clone())
+                    .collect::<Vec<String>>()
+                    .join("; "),
+                tx_builder_version: "1.13.3".to_string(),
+                created_from_safe_address: "".to_string(),
+                created_from_owner_address: "".to_string(),
+            },
+            transactions: calls
+                .iter()
+                .map(|call| SafeTransaction {
+                    to: call.to.clone(),
+                    value: "0".to_string(),
+                    data: call.data.to_string(),
+                    contract_method: None,
+                    contract_inputs_values: None,
+                })
+                .collect(),
+        })
+    }
+}
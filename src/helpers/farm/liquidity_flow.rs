@@ -0,0 +1,357 @@
+use std::{
+    collections::HashMap,
+    thread::{self, JoinHandle},
+};
+
+use graphql_client::{GraphQLQuery, Response};
+
+use crate::{
+    graphql::queries::liquidityEventsQuery::{liquidity_events_query, LiquidityEventsQuery},
+    helpers::{
+        block::block_timestamp::{now, query_block_at, BlockQueryError},
+        farm::pools_with_sushi::{query_pools_with_sushi_at, Minichef, PoolsWithSushiQueryError},
+        volume::{
+            pairs_state::{query_pairs_state, PairsStateQueryError},
+            period_volume::Period,
+        },
+    },
+    network::LEGACY_SUBGRAPH,
+    subgraph,
+};
+
+/// Liquidity added (Mint) and removed (Burn) in a pair over the period, with the share of the
+/// chain emission its farm had at both ends of the period, 0 when unfarmed.
+#[derive(Debug, Clone)]
+pub struct PairFlow {
+    pub id: String,
+    pub name: String,
+    pub added_usd: f64,
+    pub removed_usd: f64,
+    pub mints: usize,
+    pub burns: usize,
+    pub alloc_start: f64,
+    pub alloc_end: f64,
+}
+
+impl PairFlow {
+    fn new(id: String, name: String) -> PairFlow {
+        PairFlow {
+            id,
+            name,
+            added_usd: 0.0,
+            removed_usd: 0.0,
+            mints: 0,
+            burns: 0,
+            alloc_start: 0.0,
+            alloc_end: 0.0,
+        }
+    }
+
+    pub fn net_usd(&self) -> f64 {
+        self.added_usd - self.removed_usd
+    }
+
+    //change of emission share over the period, in share points
+    pub fn alloc_change(&self) -> f64 {
+        self.alloc_end - self.alloc_start
+    }
+
+    pub fn is_farmed(&self) -> bool {
+        self.alloc_start > 0.0 || self.alloc_end > 0.0
+    }
+}
+
+#[derive(Debug)]
+pub struct LiquidityFlow {
+    pub chain: String,
+    pub start_block: u64,
+    pub pairs: HashMap<String, PairFlow>,
+    pub truncated_at: Option<u64>, //end of the events fetched when max events was reached
+    pub unpriced: usize,           //events without USD amount, left out of the totals
+}
+
+impl LiquidityFlow {
+    pub fn added_usd(&self) -> f64 {
+        self.pairs.values().map(|pair| pair.added_usd).sum()
+    }
+
+    pub fn removed_usd(&self) -> f64 {
+        self.pairs.values().map(|pair| pair.removed_usd).sum()
+    }
+
+    /// Pearson correlation between the alloc change and the net flow of farmed pairs, along
+    /// the number of pairs it is computed on. None under 3 pairs or without any alloc change.
+    pub fn alloc_correlation(&self) -> Option<(f64, usize)> {
+        let points: Vec<(f64, f64)> = self
+            .pairs
+            .values()
+            .filter(|pair| pair.is_farmed())
+            .map(|pair| (pair.alloc_change(), pair.net_usd()))
+            .collect();
+        if points.len() < 3 {
+            return None;
+        }
+        let len = points.len() as f64;
+        let mean_x = points.iter().map(|point| point.0).sum::<f64>() / len;
+        let mean_y = points.iter().map(|point| point.1).sum::<f64>() / len;
+        let (mut cov, mut var_x, mut var_y) = (0.0, 0.0, 0.0);
+        for (x, y) in &points {
+            cov += (x - mean_x) * (y - mean_y);
+            var_x += (x - mean_x).powi(2);
+            var_y += (y - mean_y).powi(2);
+        }
+        if var_x == 0.0 || var_y == 0.0 {
+            return None;
+        }
+        Some((cov / (var_x * var_y).sqrt(), points.len()))
+    }
+}
+
+#[derive(Debug)]
+#[allow(dead_code)] //only read through Debug when printing errors
+pub enum LiquidityFlowError {
+    UnknownChain(String),
+    Block(String, BlockQueryError),
+    RequestError(String, String),
+    EmptyResponse(String),
+    Farms(PoolsWithSushiQueryError),
+    Pairs(PairsStateQueryError),
+}
+
+//a Mint, or a Burn if mint is false
+struct LiquidityEvent {
+    id: String,
+    timestamp: u64,
+    pair_id: String,
+    pair_name: String,
+    amount_usd: Option<String>,
+    mint: bool,
+}
+
+//position of the mints or burns paging, ordered by timestamp then id
+struct EventsCursor {
+    timestamp: u64,
+    id: String,
+    more: bool, //last page was full
+}
+
+impl EventsCursor {
+    fn new(from: u64) -> EventsCursor {
+        EventsCursor {
+            timestamp: from.saturating_sub(1), //events after it, so from included
+            id: String::new(),
+            more: true,
+        }
+    }
+
+    fn advance(&mut self, page: &[LiquidityEvent], len: usize) {
+        if let Some(last) = page.last() {
+            self.timestamp = last.timestamp;
+            self.id = last.id.clone();
+        }
+        self.more = len == 1000;
+    }
+}
+
+fn add_events(pairs: &mut HashMap<String, PairFlow>, events: Vec<LiquidityEvent>) -> usize {
+    let mut unpriced = 0;
+    for event in events {
+        let amount_usd: f64 = match event.amount_usd.and_then(|amount| amount.parse().ok()) {
+            Some(amount) => amount,
+            None => {
+                unpriced += 1;
+                continue;
+            }
+        };
+        let pair = pairs
+            .entry(event.pair_id.clone())
+            .or_insert_with(|| PairFlow::new(event.pair_id, event.pair_name));
+        if event.mint {
+            pair.added_usd += amount_usd;
+            pair.mints += 1;
+        } else {
+            pair.removed_usd += amount_usd;
+            pair.burns += 1;
+        }
+    }
+    unpriced
+}
+
+//share of the chain emission of each farmed pair, an unknown chef means no farms
+fn alloc_shares(
+    chain: &str,
+    block: Option<u64>,
+) -> Result<HashMap<String, f64>, LiquidityFlowError> {
+    let minichef: Minichef = match query_pools_with_sushi_at(chain.to_string(), block) {
+        Ok(minichef) => minichef,
+        Err(PoolsWithSushiQueryError::UnknownChain(_)) => return Ok(HashMap::new()),
+        Err(error) => return Err(LiquidityFlowError::Farms(error)),
+    };
+    if minichef.sushi_per_day == 0.0 {
+        return Ok(HashMap::new());
+    }
+    Ok(minichef
+        .pools
+        .values()
+        .map(|pool| (pool.id.clone(), pool.sushi_per_day / minichef.sushi_per_day))
+        .collect())
+}
+
+/// Mint and Burn events of a chain over the period, the first max_events in time, summed by pair
+/// along the farm alloc of each pair at the start of the period and now.
+pub fn query_liquidity_flow(
+    chain: String,
+    period: &Period,
+    max_events: usize,
+) -> Result<LiquidityFlow, LiquidityFlowError> {
+    let subgraph = match LEGACY_SUBGRAPH.get(&chain) {
+        Some(subgraph) => subgraph,
+        None => return Err(LiquidityFlowError::UnknownChain(chain)),
+    };
+    let from = now() - u64::from(86_400 * period.days);
+    let start_block = match query_block_at(&chain, from, period.block_source) {
+        Ok(block) => block.number,
+        Err(error) => return Err(LiquidityFlowError::Block(chain, error)),
+    };
+
+    let mut events: Vec<LiquidityEvent> = vec![];
+    let (mut mints, mut burns) = (EventsCursor::new(from), EventsCursor::new(from));
+    while (mints.more || burns.more) && events.len() < max_events {
+        //only page the kind lagging behind so both cover the same time
+        let with_mints = mints.more && (!burns.more || mints.timestamp <= burns.timestamp);
+        let with_burns = burns.more && (!mints.more || burns.timestamp <= mints.timestamp);
+        let request_body = LiquidityEventsQuery::build_query(liquidity_events_query::Variables {
+            mint_cursor: mints.timestamp.to_string(),
+            last_mint: mints.id.clone(),
+            burn_cursor: burns.timestamp.to_string(),
+            last_burn: burns.id.clone(),
+            with_mints,
+            with_burns,
+        });
+        let res: Response<liquidity_events_query::ResponseData> =
+            match subgraph::query_subgraph(subgraph, &request_body) {
+                Ok(res) => res,
+                Err(error) => {
+                    return Err(LiquidityFlowError::RequestError(chain, error.to_string()))
+                }
+            };
+        let data = match res.data {
+            Some(data) => data,
+            None => return Err(LiquidityFlowError::EmptyResponse(chain)),
+        };
+
+        if let Some(page) = data.mints {
+            let len = page.len();
+            let page: Vec<LiquidityEvent> = page
+                .into_iter()
+                .filter_map(|mint| {
+                    Some(LiquidityEvent {
+                        id: mint.id,
+                        timestamp: mint.timestamp.parse().ok()?,
+                        pair_id: mint.pair.id,
+                        pair_name: mint.pair.name,
+                        amount_usd: mint.amount_usd,
+                        mint: true,
+                    })
+                })
+                .collect();
+            mints.advance(&page, len);
+            events.extend(page);
+        }
+        if let Some(page) = data.burns {
+            let len = page.len();
+            let page: Vec<LiquidityEvent> = page
+                .into_iter()
+                .filter_map(|burn| {
+                    Some(LiquidityEvent {
+                        id: burn.id,
+                        timestamp: burn.timestamp.parse().ok()?,
+                        pair_id: burn.pair.id,
+                        pair_name: burn.pair.name,
+                        amount_usd: burn.amount_usd,
+                        mint: false,
+                    })
+                })
+                .collect();
+            burns.advance(&page, len);
+            events.extend(page);
+        }
+    }
+
+    //past the cursor of a kind still paging, events of that kind may be missing
+    let frontier = [&mints, &burns]
+        .into_iter()
+        .filter(|cursor| cursor.more)
+        .map(|cursor| cursor.timestamp)
+        .min();
+    events.retain(|event| frontier.is_none_or(|frontier| event.timestamp < frontier));
+    events.sort_by(|a, b| (a.timestamp, &a.id).cmp(&(b.timestamp, &b.id)));
+    let mut truncated_at = frontier;
+    if events.len() > max_events {
+        events.truncate(max_events);
+        truncated_at = events.last().map(|event| event.timestamp);
+    }
+    let mut pairs: HashMap<String, PairFlow> = HashMap::new();
+    let unpriced = add_events(&mut pairs, events);
+
+    let alloc_start = alloc_shares(&chain, Some(start_block))?;
+    let alloc_end = alloc_shares(&chain, None)?;
+    //farmed pairs without any event still count for the correlation
+    let mut missing: Vec<String> = alloc_start
+        .keys()
+        .chain(alloc_end.keys())
+        .filter(|id| !pairs.contains_key(*id))
+        .cloned()
+        .collect();
+    missing.sort_unstable();
+    missing.dedup();
+    if !missing.is_empty() {
+        let states = match query_pairs_state(&chain, &missing, None) {
+            Ok(states) => states,
+            Err(error) => return Err(LiquidityFlowError::Pairs(error)),
+        };
+        for state in states.into_values() {
+            pairs.insert(state.id.clone(), PairFlow::new(state.id, state.name));
+        }
+    }
+    for pair in pairs.values_mut() {
+        pair.alloc_start = *alloc_start.get(&pair.id).unwrap_or(&0.0);
+        pair.alloc_end = *alloc_end.get(&pair.id).unwrap_or(&0.0);
+    }
+
+    Ok(LiquidityFlow {
+        chain,
+        start_block,
+        pairs,
+        truncated_at,
+        unpriced,
+    })
+}
+
+type ChainFlowHandle = JoinHandle<Result<LiquidityFlow, LiquidityFlowError>>;
+
+pub fn query_liquidity_flow_multichain(
+    chains: Vec<String>,
+    period: Period,
+    max_events: usize,
+) -> Vec<LiquidityFlow> {
+    let mut handles: Vec<ChainFlowHandle> = vec![];
+    for chain in chains {
+        let period = period.clone();
+        handles.push(thread::spawn(move || {
+            query_liquidity_flow(chain, &period, max_events)
+        }));
+    }
+
+    let mut result: Vec<LiquidityFlow> = vec![];
+    for handle in handles {
+        match handle.join().unwrap() {
+            Ok(flow) => result.push(flow),
+            Err(error) => {
+                eprintln!("Error while querying liquidity flow: {:#?}", error);
+                continue;
+            }
+        };
+    }
+    result
+}
@@ -1 +1,9 @@
+pub mod apr;
+pub mod audit;
+pub mod candidates;
+pub mod chef_calls;
+pub mod liquidity_flow;
+pub mod optimizer;
 pub mod pools_with_sushi;
+pub mod reconcile;
+pub mod runway;
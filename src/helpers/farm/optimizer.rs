@@ -0,0 +1,181 @@
+use std::collections::HashMap;
+
+use crate::helpers::farm::pools_with_sushi::Minichef;
+
+//the alloc points are distributed in this many steps at most
+const ALLOCATION_STEPS: u32 = 1_000;
+
+#[derive(Debug)]
+pub struct OptimizerConstraints {
+    pub budget: f64, //max sushi per day for the chain
+    pub min_alloc: u32,
+    pub max_alloc: Option<u32>,
+    pub protected: Vec<String>, //pairs keeping their current alloc point
+    pub elasticity: f64,
+}
+
+#[derive(Debug, Clone)]
+pub struct AllocProposal {
+    pub pair: String,
+    pub pid: u32,
+    pub protected: bool,
+    pub current_alloc: u32,
+    pub proposed_alloc: u32,
+    pub current_sushi_per_day: f64,
+    pub proposed_sushi_per_day: f64,
+    pub current_fees_per_day: f64,
+    pub expected_fees_per_day: f64,
+}
+
+/// Proposed alloc points with the total alloc point and emission they need. The chef emission
+/// is shared by alloc point, so unassigned points only save SUSHI if the emission is lowered.
+#[derive(Debug)]
+pub struct AllocPlan {
+    pub proposals: Vec<AllocProposal>,
+    pub total_alloc_point: u32,
+    pub sushi_per_day: f64,
+    pub current_sushi_per_day: f64,
+}
+
+impl AllocPlan {
+    /// New `sushiPerSecond` in wei when the emission changes, None when it stays the same.
+    pub fn sushi_per_second(&self) -> Option<u128> {
+        let relative_change = (self.sushi_per_day - self.current_sushi_per_day).abs()
+            / self.current_sushi_per_day.max(f64::MIN_POSITIVE);
+        match relative_change {
+            change if change > 1e-9 => Some((self.sushi_per_day / 86_400.0 * 1e18) as u128),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug)]
+#[allow(dead_code)] //only read through Debug when printing errors
+pub enum OptimizerError {
+    EmptyChef,
+    MinAllocAboveBudget(u32, u32),
+}
+
+/// Expected treasury fees per day for a given spend, assuming fees scale with
+/// incentives as `((spend + reference) / (current_spend + reference)) ^ elasticity`.
+fn expected_fees(
+    current_fees: f64,
+    current_spend: f64,
+    spend: f64,
+    reference: f64,
+    elasticity: f64,
+) -> f64 {
+    current_fees * ((spend + reference) / (current_spend + reference)).powf(elasticity)
+}
+
+/// Propose new alloc points maximising expected treasury fees minus sushi spent.
+///
+/// Alloc points are handed out greedily to the pool with the best marginal net revenue
+/// until the budget (the current total alloc point worth `budget` sushi per day) is used
+/// or no pool earns more than it costs. The emission of the plan is what the handed out
+/// points are worth, so it can be lower than the budget.
+pub fn optimize_allocations(
+    minichef: &Minichef,
+    fees_per_day: &HashMap<String, f64>,
+    sushi_price: f64,
+    constraints: &OptimizerConstraints,
+) -> Result<AllocPlan, OptimizerError> {
+    let total_alloc_point = minichef.total_alloc_point;
+    if minichef.pools.is_empty() || total_alloc_point == 0 {
+        return Err(OptimizerError::EmptyChef);
+    }
+    let sushi_per_point = constraints.budget / total_alloc_point as f64;
+    //average spend per pool, added to both sides of the fee response so unfarmed pools
+    //still respond to incentives without their growth being unbounded
+    let reference = constraints.budget / minichef.pools.len() as f64;
+    let max_alloc = constraints.max_alloc.unwrap_or(total_alloc_point);
+
+    let mut proposals: Vec<AllocProposal> = minichef
+        .pools
+        .values()
+        .map(|pool| {
+            let protected = constraints.protected.contains(&pool.id);
+            AllocProposal {
+                pair: pool.id.clone(),
+                pid: pool.pid,
+                protected,
+                current_alloc: pool.alloc_point,
+                proposed_alloc: if protected {
+                    pool.alloc_point
+                } else {
+                    constraints.min_alloc
+                },
+                current_sushi_per_day: pool.sushi_per_day,
+                proposed_sushi_per_day: 0.0,
+                current_fees_per_day: *fees_per_day.get(&pool.id).unwrap_or(&0.0),
+                expected_fees_per_day: 0.0,
+            }
+        })
+        .collect();
+
+    let allocated: u32 = proposals
+        .iter()
+        .map(|proposal| proposal.proposed_alloc)
+        .sum();
+    if allocated > total_alloc_point {
+        return Err(OptimizerError::MinAllocAboveBudget(
+            allocated,
+            total_alloc_point,
+        ));
+    }
+    let mut remaining = total_alloc_point - allocated;
+    let step = (total_alloc_point / ALLOCATION_STEPS).max(1);
+
+    let fees_at = |proposal: &AllocProposal, alloc: u32| {
+        expected_fees(
+            proposal.current_fees_per_day,
+            proposal.current_sushi_per_day * sushi_price,
+            alloc as f64 * sushi_per_point * sushi_price,
+            reference * sushi_price,
+            constraints.elasticity,
+        )
+    };
+
+    while remaining > 0 {
+        let step = step.min(remaining);
+        let mut best: Option<(usize, f64)> = None;
+        for (index, proposal) in proposals.iter().enumerate() {
+            if proposal.protected || proposal.proposed_alloc + step > max_alloc {
+                continue;
+            }
+            let gain = fees_at(proposal, proposal.proposed_alloc + step)
+                - fees_at(proposal, proposal.proposed_alloc)
+                - step as f64 * sushi_per_point * sushi_price;
+            if best.is_none_or(|(_, best_gain)| gain > best_gain) {
+                best = Some((index, gain));
+            }
+        }
+        match best {
+            Some((index, gain)) if gain > 0.0 => {
+                proposals[index].proposed_alloc += step;
+                remaining -= step;
+            }
+            _ => break, //nothing left worth its cost
+        }
+    }
+
+    //pools share the new emission by their part of the new total alloc point
+    let new_total_alloc_point: u32 = proposals
+        .iter()
+        .map(|proposal| proposal.proposed_alloc)
+        .sum();
+    let sushi_per_day = new_total_alloc_point as f64 * sushi_per_point;
+    for proposal in proposals.iter_mut() {
+        proposal.proposed_sushi_per_day = match new_total_alloc_point {
+            0 => 0.0,
+            total => proposal.proposed_alloc as f64 / total as f64 * sushi_per_day,
+        };
+        proposal.expected_fees_per_day = fees_at(proposal, proposal.proposed_alloc);
+    }
+    Ok(AllocPlan {
+        proposals,
+        total_alloc_point: new_total_alloc_point,
+        sushi_per_day,
+        current_sushi_per_day: minichef.sushi_per_day,
+    })
+}
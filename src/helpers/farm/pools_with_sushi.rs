@@ -14,6 +14,7 @@ use crate::{
             minichef_pools::{self, FarmsPools},
             MinichefPools,
         },
+        minichefPoolsByPairs::{minichef_pools_by_pairs, MinichefPoolsByPairs},
     },
     network::{
         MASTERCHEFV2_SUBGRAPH, MASTERCHEFV2_TOKEN, MASTERCHEF_DUMMY_TOKENS, MASTERCHEF_SUBGRAPH,
@@ -22,16 +23,29 @@ use crate::{
     subgraph,
 };
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Pool {
     pub id: String,
+    pub pid: u32, //on mainnet, pid in MasterChef v1 or v2 depending on the pool
     pub alloc_point: u32,
     pub sushi_per_day: f64,
+    pub chef: String,     //address of the chef the pid belongs to
+    pub slp_balance: f64, //LP tokens staked, in wei for every chef
+    pub apr: Option<PoolApr>,
+}
+
+/// Staked LP value and yearly yields of a pool, see helpers::farm::apr.
+#[derive(Debug, Clone, Copy)]
+pub struct PoolApr {
+    pub staked_tvl_usd: f64,
+    pub reward_apr: f64,
+    pub fee_apr: f64,
 }
 
 impl Pool {
     pub fn from(
         data: minichef_pools::FarmsPools,
+        chef: &str,
         total_alloc_point: u32,
         total_sushi_per_day: f64,
     ) -> Option<Pool> {
@@ -42,20 +56,30 @@ impl Pool {
 
         let sushi_per_day = alloc_point as f64 / total_alloc_point as f64 * total_sushi_per_day;
 
+        let pid: u32 = match data.id.parse() {
+            Ok(pid) => pid,
+            Err(_) => return None,
+        };
+
         Some(Pool {
             id: data.pair,
+            pid,
             alloc_point,
             sushi_per_day,
+            chef: chef.to_string(),
+            slp_balance: data.slp_balance.parse().unwrap_or_default(),
+            apr: None,
         })
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Minichef {
     pub id: String,
     pub sushi_per_day: f64,
     pub total_alloc_point: u32,
     pub pools: HashMap<String, Pool>,
+    pub dummy_pools: HashMap<String, Pool>, //mainnet only, pools funding other chains by dummy token
 }
 
 impl Minichef {
@@ -81,10 +105,11 @@ impl Minichef {
             sushi_per_day,
             total_alloc_point,
             pools: HashMap::new(),
+            dummy_pools: HashMap::new(),
         };
 
         for pool_data in data.pools {
-            let pool = Pool::from(pool_data, total_alloc_point, sushi_per_day);
+            let pool = Pool::from(pool_data, &minichef.id, total_alloc_point, sushi_per_day);
             match pool {
                 Some(pool) => minichef.pools.insert(pool.id.clone(), pool),
                 None => break,
@@ -125,27 +150,32 @@ impl Minichef {
             sushi_per_day,
             total_alloc_point: msv1_alloc_point,
             pools: HashMap::new(),
+            dummy_pools: HashMap::new(),
         };
 
         let mut msv2_pool: Option<Pool> = None;
         for pool_data in msv1.pools {
-            let pool = match Pool::from(
+            let mut pool = match Pool::from(
                 FarmsPools {
                     id: pool_data.id,
                     pair: pool_data.pair,
                     alloc_point: pool_data.alloc_point,
+                    slp_balance: pool_data.slp_balance,
                 },
+                &minichef.id,
                 msv1_alloc_point,
                 sushi_per_day,
             ) {
                 Some(pool) => pool,
                 None => break,
             };
+            pool.slp_balance *= 1e18; //MasterChef v1 subgraph gives whole LP tokens, not wei
             if pool.id.contains(MASTERCHEFV2_TOKEN) {
                 minichef.sushi_per_day -= pool.sushi_per_day; //remove from total
                 msv2_pool = Some(pool); //save msv2 and don't add it to pools
-            } else if MASTERCHEF_DUMMY_TOKENS.contains(&pool.id.as_str()) {
-                minichef.sushi_per_day -= pool.sushi_per_day; //remove from total and don't add pool
+            } else if MASTERCHEF_DUMMY_TOKENS.contains_key(pool.id.as_str()) {
+                minichef.sushi_per_day -= pool.sushi_per_day; //remove from total and keep apart
+                minichef.dummy_pools.insert(pool.id.clone(), pool);
             } else {
                 minichef.pools.insert(pool.id.clone(), pool);
             }
@@ -158,7 +188,9 @@ impl Minichef {
                         id: pool_data_v2.id,
                         pair: pool_data_v2.pair,
                         alloc_point: pool_data_v2.alloc_point,
+                        slp_balance: pool_data_v2.slp_balance,
                     },
+                    &msv2.master_chefs[0].id,
                     msv2_alloc_point,
                     msv2_pool.sushi_per_day, //sushi given to msv2 pool by msv1
                 ) {
@@ -176,7 +208,58 @@ impl Minichef {
     }
 }
 
+impl Minichef {
+    /// Copy of a MiniChef with new alloc points for some pools (keyed by pair) and/or a new
+    /// daily emission, pools sushi per day are recomputed from the new total alloc point.
+    /// Not meant for mainnet, where MasterChef v2 pools share the emission of a v1 pool.
+    pub fn with_overrides(
+        &self,
+        sushi_per_day: Option<f64>,
+        alloc_points: &HashMap<String, u32>,
+    ) -> Minichef {
+        let mut minichef = self.clone();
+
+        let mut total_alloc_point = self.total_alloc_point as i64;
+        for (pair, alloc_point) in alloc_points {
+            match self.pools.get(pair) {
+                Some(pool) => total_alloc_point += *alloc_point as i64 - pool.alloc_point as i64,
+                None => eprintln!("No farm for {pair}, alloc point ignored."),
+            }
+        }
+        let total_alloc_point = total_alloc_point.max(1) as u32;
+
+        for pool in minichef.pools.values_mut() {
+            let alloc_point = *alloc_points.get(&pool.id).unwrap_or(&pool.alloc_point);
+            let sushi_per_day = alloc_point as f64 / total_alloc_point as f64 * self.sushi_per_day;
+            minichef.sushi_per_day += sushi_per_day - pool.sushi_per_day;
+            pool.alloc_point = alloc_point;
+            pool.sushi_per_day = sushi_per_day;
+        }
+        minichef.total_alloc_point = total_alloc_point;
+
+        if let Some(sushi_per_day) = sushi_per_day {
+            //without any alloc point left no pool receives the emission, the chef spends 0
+            if minichef
+                .pools
+                .values()
+                .all(|pool| pool.sushi_per_day == 0.0)
+            {
+                minichef.sushi_per_day = 0.0;
+                return minichef;
+            }
+            let ratio = sushi_per_day / minichef.sushi_per_day;
+            for pool in minichef.pools.values_mut() {
+                pool.sushi_per_day *= ratio;
+            }
+            minichef.sushi_per_day = sushi_per_day;
+        }
+
+        minichef
+    }
+}
+
 #[derive(Debug)]
+#[allow(dead_code)] //only read through Debug when printing errors
 pub enum PoolsWithSushiQueryError {
     UnknownChain(String),
     RequestError(String, String),
@@ -184,9 +267,17 @@ pub enum PoolsWithSushiQueryError {
     ParsingMinichef(String),
 }
 
-fn query_mainnet_pools_with_sushi() -> Result<Minichef, PoolsWithSushiQueryError> {
+fn query_mainnet_pools_with_sushi(
+    block: Option<u64>,
+) -> Result<Minichef, PoolsWithSushiQueryError> {
     let chain = "ethereum".to_string();
-    let msv1_query = MasterchefPools::build_query(masterchef_pools::Variables);
+    let msv1_query = MasterchefPools::build_query(masterchef_pools::Variables {
+        block: block.map(|block| masterchef_pools::Block_height {
+            hash: None,
+            number: Some(block as i64),
+            number_gte: None,
+        }),
+    });
 
     let msv1: Response<masterchef_pools::ResponseData> =
         match subgraph::query_subgraph(MASTERCHEF_SUBGRAPH, &msv1_query) {
@@ -204,7 +295,13 @@ fn query_mainnet_pools_with_sushi() -> Result<Minichef, PoolsWithSushiQueryError
         None => return Err(PoolsWithSushiQueryError::EmptyResponse(chain)),
     };
 
-    let msv2_query = MasterChefv2Pools::build_query(masterchefv2_pools::Variables);
+    let msv2_query = MasterChefv2Pools::build_query(masterchefv2_pools::Variables {
+        block: block.map(|block| masterchefv2_pools::Block_height {
+            hash: None,
+            number: Some(block as i64),
+            number_gte: None,
+        }),
+    });
 
     let msv2: Response<masterchefv2_pools::ResponseData> =
         match subgraph::query_subgraph(MASTERCHEFV2_SUBGRAPH, &msv2_query) {
@@ -228,16 +325,131 @@ fn query_mainnet_pools_with_sushi() -> Result<Minichef, PoolsWithSushiQueryError
     }
 }
 
+//pids of the pools with alloc points, to mass update them all before a set()
+fn parse_active_pids<'a>(ids: impl Iterator<Item = &'a String>) -> Option<Vec<u32>> {
+    ids.map(|id| id.parse().ok()).collect()
+}
+
+/// Pids of every MasterChef v1 pool with alloc points, dummy and MasterChef v2 pools included.
+pub fn query_masterchef_active_pids() -> Result<Vec<u32>, PoolsWithSushiQueryError> {
+    let chain = "ethereum".to_string();
+    let request_body = MasterchefPools::build_query(masterchef_pools::Variables { block: None });
+    let res: Response<masterchef_pools::ResponseData> =
+        match subgraph::query_subgraph(MASTERCHEF_SUBGRAPH, &request_body) {
+            Ok(res) => res,
+            Err(error) => {
+                return Err(PoolsWithSushiQueryError::RequestError(
+                    chain,
+                    error.to_string(),
+                ))
+            }
+        };
+    let data = match res.data {
+        Some(data) => data,
+        None => return Err(PoolsWithSushiQueryError::EmptyResponse(chain)),
+    };
+    match parse_active_pids(data.pools.iter().map(|pool| &pool.id)) {
+        Some(pids) => Ok(pids),
+        None => Err(PoolsWithSushiQueryError::ParsingMinichef(chain)),
+    }
+}
+
+/// Pids of every MasterChef v2 pool with alloc points.
+pub fn query_masterchefv2_active_pids() -> Result<Vec<u32>, PoolsWithSushiQueryError> {
+    let chain = "ethereum".to_string();
+    let request_body =
+        MasterChefv2Pools::build_query(masterchefv2_pools::Variables { block: None });
+    let res: Response<masterchefv2_pools::ResponseData> =
+        match subgraph::query_subgraph(MASTERCHEFV2_SUBGRAPH, &request_body) {
+            Ok(res) => res,
+            Err(error) => {
+                return Err(PoolsWithSushiQueryError::RequestError(
+                    chain,
+                    error.to_string(),
+                ))
+            }
+        };
+    let data = match res.data {
+        Some(data) => data,
+        None => return Err(PoolsWithSushiQueryError::EmptyResponse(chain)),
+    };
+    match parse_active_pids(data.pools.iter().map(|pool| &pool.id)) {
+        Some(pids) => Ok(pids),
+        None => Err(PoolsWithSushiQueryError::ParsingMinichef(chain)),
+    }
+}
+
+/// Pools of a MiniChef staking the given pairs, including the ones without alloc points left
+/// out of query_pools_with_sushi.
+pub fn query_minichef_pools_by_pairs(
+    chain: &str,
+    minichef: &Minichef,
+    pairs: Vec<String>,
+) -> Result<Vec<Pool>, PoolsWithSushiQueryError> {
+    let subgraph = match MINICHEF_SUBGRAPH.get(chain) {
+        Some(subgraph) => subgraph,
+        None => return Err(PoolsWithSushiQueryError::UnknownChain(chain.to_string())),
+    };
+    let request_body =
+        MinichefPoolsByPairs::build_query(minichef_pools_by_pairs::Variables { pairs });
+    let res: Response<minichef_pools_by_pairs::ResponseData> =
+        match subgraph::query_subgraph(subgraph, &request_body) {
+            Ok(res) => res,
+            Err(error) => {
+                return Err(PoolsWithSushiQueryError::RequestError(
+                    chain.to_string(),
+                    error.to_string(),
+                ))
+            }
+        };
+    let data = match res.data {
+        Some(data) => data,
+        None => return Err(PoolsWithSushiQueryError::EmptyResponse(chain.to_string())),
+    };
+    let mut pools = vec![];
+    for pool_data in data.pools {
+        match Pool::from(
+            FarmsPools {
+                id: pool_data.id,
+                pair: pool_data.pair,
+                alloc_point: pool_data.alloc_point,
+                slp_balance: pool_data.slp_balance,
+            },
+            &minichef.id,
+            minichef.total_alloc_point,
+            minichef.sushi_per_day,
+        ) {
+            Some(pool) => pools.push(pool),
+            None => return Err(PoolsWithSushiQueryError::ParsingMinichef(chain.to_string())),
+        }
+    }
+    Ok(pools)
+}
+
 pub fn query_pools_with_sushi(chain: String) -> Result<Minichef, PoolsWithSushiQueryError> {
+    query_pools_with_sushi_at(chain, None)
+}
+
+/// Chef pools as they were at a block of the chain, subgraph head if None.
+pub fn query_pools_with_sushi_at(
+    chain: String,
+    block: Option<u64>,
+) -> Result<Minichef, PoolsWithSushiQueryError> {
     if chain.contains("ethereum") {
-        return query_mainnet_pools_with_sushi();
+        return query_mainnet_pools_with_sushi(block);
     }
     let subgraph = match MINICHEF_SUBGRAPH.get(&chain) {
         Some(subgraph) => subgraph,
         None => return Err(PoolsWithSushiQueryError::UnknownChain(chain)),
     };
 
-    let minichef_pools_query = MinichefPools::build_query(minichef_pools::Variables);
+    let minichef_pools_query = MinichefPools::build_query(minichef_pools::Variables {
+        block: block.map(|block| minichef_pools::Block_height {
+            hash: None,
+            number: Some(block as i64),
+            number_gte: None,
+        }),
+    });
 
     let minichef: Response<minichef_pools::ResponseData> =
         match subgraph::query_subgraph(subgraph, &minichef_pools_query) {
@@ -261,12 +473,21 @@ pub fn query_pools_with_sushi(chain: String) -> Result<Minichef, PoolsWithSushiQ
 }
 
 pub fn query_multichain_pools_with_sushi(chains: Vec<String>) -> HashMap<String, Minichef> {
+    query_multichain_pools_with_sushi_at(chains.into_iter().map(|chain| (chain, None)).collect())
+}
+
+/// Chef pools of every chain at its own block, subgraph head if None.
+pub fn query_multichain_pools_with_sushi_at(
+    chains: Vec<(String, Option<u64>)>,
+) -> HashMap<String, Minichef> {
     let mut handles: Vec<JoinHandle<Result<(String, Minichef), PoolsWithSushiQueryError>>> = vec![];
-    for chain in chains {
-        let handle = thread::spawn(move || match query_pools_with_sushi(chain.clone()) {
-            Ok(minichef) => Ok((chain, minichef)),
-            Err(error) => Err(error),
-        });
+    for (chain, block) in chains {
+        let handle = thread::spawn(
+            move || match query_pools_with_sushi_at(chain.clone(), block) {
+                Ok(minichef) => Ok((chain, minichef)),
+                Err(error) => Err(error),
+            },
+        );
 
         handles.push(handle);
     }
@@ -0,0 +1,106 @@
+use std::collections::HashMap;
+
+use crate::{
+    helpers::farm::pools_with_sushi::Minichef,
+    network::{MASTERCHEF_DUMMY_TOKENS, REDUCE_DUMMY_CHAIN},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FundingStatus {
+    Balanced,
+    OverFunded,
+    UnderFunded,
+    NotFunded,  //minichef emitting without any dummy pool on mainnet
+    NoMinichef, //dummy pool without a known minichef on the chain
+    NotEmitted, //reduce dummy pool, nothing bridged
+}
+
+/// SUSHI minted on mainnet for a chain through its dummy pools against what its minichef emits.
+#[derive(Debug, Clone)]
+pub struct ChainFunding {
+    pub chain: String,
+    pub pids: Vec<u32>,
+    pub mainnet_sushi_per_day: f64,
+    pub minichef_sushi_per_day: Option<f64>,
+    pub status: FundingStatus,
+}
+
+impl ChainFunding {
+    pub fn difference(&self) -> Option<f64> {
+        self.minichef_sushi_per_day
+            .map(|minichef_sushi_per_day| self.mainnet_sushi_per_day - minichef_sushi_per_day)
+    }
+}
+
+fn funding_status(
+    chain: &str,
+    mainnet_sushi_per_day: f64,
+    minichef_sushi_per_day: Option<f64>,
+    tolerance: f64,
+) -> FundingStatus {
+    if chain == REDUCE_DUMMY_CHAIN {
+        return FundingStatus::NotEmitted;
+    }
+    let minichef_sushi_per_day = match minichef_sushi_per_day {
+        Some(sushi_per_day) => sushi_per_day,
+        None => return FundingStatus::NoMinichef,
+    };
+    if mainnet_sushi_per_day == 0.0 {
+        return match minichef_sushi_per_day == 0.0 {
+            true => FundingStatus::Balanced,
+            false => FundingStatus::NotFunded,
+        };
+    }
+    let ratio = mainnet_sushi_per_day / minichef_sushi_per_day;
+    if ratio > 1.0 + tolerance {
+        FundingStatus::OverFunded
+    } else if ratio < 1.0 - tolerance {
+        FundingStatus::UnderFunded
+    } else {
+        FundingStatus::Balanced
+    }
+}
+
+/// Group mainnet dummy pools by destination chain and compare them with each minichef emission,
+/// tolerance is the accepted relative gap before flagging a chain (0.05 = 5%).
+pub fn reconcile_funding(
+    mainnet: &Minichef,
+    minichefs: &HashMap<String, Minichef>,
+    tolerance: f64,
+) -> Vec<ChainFunding> {
+    let mut fundings: HashMap<String, (Vec<u32>, f64)> = HashMap::new();
+    for pool in mainnet.dummy_pools.values() {
+        let chain = match MASTERCHEF_DUMMY_TOKENS.get(pool.id.as_str()) {
+            Some(chain) => chain.to_string(),
+            None => continue,
+        };
+        let funding = fundings.entry(chain).or_default();
+        funding.0.push(pool.pid);
+        funding.1 += pool.sushi_per_day;
+    }
+    //chains emitting without being funded by a dummy pool
+    for chain in minichefs.keys() {
+        fundings.entry(chain.clone()).or_default();
+    }
+
+    fundings
+        .into_iter()
+        .map(|(chain, (mut pids, mainnet_sushi_per_day))| {
+            pids.sort();
+            let minichef_sushi_per_day =
+                minichefs.get(&chain).map(|minichef| minichef.sushi_per_day);
+            ChainFunding {
+                status: funding_status(
+                    &chain,
+                    mainnet_sushi_per_day,
+                    minichef_sushi_per_day,
+                    tolerance,
+                ),
+                chain,
+                pids,
+                mainnet_sushi_per_day,
+                minichef_sushi_per_day,
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,215 @@
+use std::{
+    collections::HashMap,
+    thread::{self, JoinHandle},
+};
+
+use ethers::utils::format_units;
+use graphql_client::{GraphQLQuery, Response};
+
+use crate::{
+    graphql::queries::{
+        minichefRewards::{minichef_rewards, MinichefRewards},
+        minichefUsers::{minichef_users, MinichefUsers},
+    },
+    helpers::{
+        block::block_timestamp::now,
+        erc20::query_balance_of,
+        farm::pools_with_sushi::{query_pools_with_sushi, PoolsWithSushiQueryError},
+    },
+    network::{MINICHEF_ADDRESS, MINICHEF_SUBGRAPH},
+    subgraph,
+};
+
+const ACC_SUSHI_PRECISION: f64 = 1e12;
+const MAX_USER_PAGES: usize = 50; //50k stakers, enough for every minichef so far
+
+#[derive(Debug, Clone)]
+pub struct Runway {
+    pub chain: String,
+    pub balance: f64,
+    pub unclaimed: f64,
+    pub sushi_per_day: f64,
+    pub truncated: bool, //true if not every staker could be fetched
+}
+
+impl Runway {
+    //SUSHI left once every staker has harvested
+    pub fn available(&self) -> f64 {
+        self.balance - self.unclaimed
+    }
+
+    pub fn days_left(&self) -> f64 {
+        if self.sushi_per_day == 0.0 {
+            return f64::INFINITY;
+        }
+        (self.available() / self.sushi_per_day).max(0.0)
+    }
+
+    pub fn top_up(&self, target_days: f64) -> f64 {
+        (self.sushi_per_day * target_days - self.available()).max(0.0)
+    }
+}
+
+#[derive(Debug)]
+#[allow(dead_code)] //only read through Debug when printing errors
+pub enum RunwayQueryError {
+    UnknownChain(String),
+    PoolsQueryError(PoolsWithSushiQueryError),
+    RequestError(String, String),
+    EmptyResponse(String),
+    ParsingMinichef(String),
+    BalanceError(String, String),
+}
+
+//accSushiPerShare of a pool brought up to now, as updatePool would do it
+fn current_acc_sushi_per_share(
+    pool: &minichef_rewards::MinichefRewardsPools,
+    sushi_per_second: f64,
+    total_alloc_point: f64,
+    timestamp: u64,
+) -> Option<f64> {
+    let acc_sushi_per_share: f64 = pool.acc_sushi_per_share.parse().ok()?;
+    let alloc_point: f64 = pool.alloc_point.parse().ok()?;
+    let last_reward_time: u64 = pool.last_reward_time.parse().ok()?;
+    let slp_balance: f64 = pool.slp_balance.parse().ok()?;
+    if slp_balance == 0.0 || total_alloc_point == 0.0 {
+        return Some(acc_sushi_per_share);
+    }
+
+    let elapsed = timestamp.saturating_sub(last_reward_time) as f64;
+    let sushi_reward = elapsed * sushi_per_second * alloc_point / total_alloc_point;
+    Some(acc_sushi_per_share + sushi_reward * ACC_SUSHI_PRECISION / slp_balance)
+}
+
+//sum of pendingSushi over every staker, in wei
+fn query_unclaimed(
+    chain: &str,
+    subgraph: &str,
+    acc_sushi_per_share: &HashMap<String, f64>,
+) -> Result<(f64, bool), RunwayQueryError> {
+    let mut unclaimed = 0.0;
+    let mut last_id = String::new();
+    for _ in 0..MAX_USER_PAGES {
+        let users_query = MinichefUsers::build_query(minichef_users::Variables {
+            last_id: last_id.clone(),
+        });
+        let users: Response<minichef_users::ResponseData> =
+            match subgraph::query_subgraph(subgraph, &users_query) {
+                Ok(users) => users,
+                Err(error) => {
+                    return Err(RunwayQueryError::RequestError(
+                        chain.to_string(),
+                        error.to_string(),
+                    ))
+                }
+            };
+        let users = match users.data {
+            Some(data) => data.users,
+            None => return Err(RunwayQueryError::EmptyResponse(chain.to_string())),
+        };
+        let page_size = users.len();
+
+        for user in users {
+            last_id = user.id;
+            let acc = match user
+                .pool
+                .and_then(|pool| acc_sushi_per_share.get(&pool.id).copied())
+            {
+                Some(acc) => acc,
+                None => continue, //pool without staked lp, nothing pending
+            };
+            let amount: f64 = user.amount.parse().unwrap_or_default();
+            let reward_debt: f64 = user.reward_debt.parse().unwrap_or_default();
+            unclaimed += (amount * acc / ACC_SUSHI_PRECISION - reward_debt).max(0.0);
+        }
+
+        if page_size < 1000 {
+            return Ok((unclaimed, false));
+        }
+    }
+    Ok((unclaimed, true))
+}
+
+pub fn query_runway(chain: String) -> Result<Runway, RunwayQueryError> {
+    let (subgraph, minichef_address) =
+        match (MINICHEF_SUBGRAPH.get(&chain), MINICHEF_ADDRESS.get(&chain)) {
+            (Some(subgraph), Some(address)) => (subgraph, address),
+            _ => return Err(RunwayQueryError::UnknownChain(chain)),
+        };
+
+    let minichef = match query_pools_with_sushi(chain.clone()) {
+        Ok(minichef) => minichef,
+        Err(error) => return Err(RunwayQueryError::PoolsQueryError(error)),
+    };
+
+    let rewards_query = MinichefRewards::build_query(minichef_rewards::Variables);
+    let rewards: Response<minichef_rewards::ResponseData> =
+        match subgraph::query_subgraph(subgraph, &rewards_query) {
+            Ok(rewards) => rewards,
+            Err(error) => return Err(RunwayQueryError::RequestError(chain, error.to_string())),
+        };
+    let rewards = match rewards.data {
+        Some(data) => data,
+        None => return Err(RunwayQueryError::EmptyResponse(chain)),
+    };
+    let chef = match rewards.mini_chefs.first() {
+        Some(chef) => chef,
+        None => return Err(RunwayQueryError::ParsingMinichef(chain)),
+    };
+    let (sushi_per_second, total_alloc_point) = match (
+        chef.sushi_per_second.parse::<f64>(),
+        chef.total_alloc_point.parse::<f64>(),
+    ) {
+        (Ok(sushi_per_second), Ok(total_alloc_point)) => (sushi_per_second, total_alloc_point),
+        _ => return Err(RunwayQueryError::ParsingMinichef(chain)),
+    };
+
+    let timestamp = now();
+    let mut acc_sushi_per_share: HashMap<String, f64> = HashMap::new();
+    for pool in rewards.pools.iter() {
+        match current_acc_sushi_per_share(pool, sushi_per_second, total_alloc_point, timestamp) {
+            Some(acc) => acc_sushi_per_share.insert(pool.id.clone(), acc),
+            None => return Err(RunwayQueryError::ParsingMinichef(chain)),
+        };
+    }
+    let (unclaimed, truncated) = query_unclaimed(&chain, subgraph, &acc_sushi_per_share)?;
+
+    let balance = match query_balance_of(&chain, &chef.sushi, minichef_address) {
+        Ok(balance) => balance,
+        Err(error) => return Err(RunwayQueryError::BalanceError(chain, error.to_string())),
+    };
+    let balance: f64 = match format_units(balance, 18) {
+        Ok(balance) => balance.parse().unwrap(),
+        Err(error) => return Err(RunwayQueryError::BalanceError(chain, error.to_string())),
+    };
+    let unclaimed = unclaimed / 1e18; //sushi has 18 decimals
+
+    Ok(Runway {
+        chain,
+        balance,
+        unclaimed,
+        sushi_per_day: minichef.sushi_per_day,
+        truncated,
+    })
+}
+
+type ChainRunwayHandle = JoinHandle<Result<Runway, RunwayQueryError>>;
+
+pub fn query_multichain_runway(chains: Vec<String>) -> HashMap<String, Runway> {
+    let mut handles: Vec<ChainRunwayHandle> = vec![];
+    for chain in chains {
+        handles.push(thread::spawn(move || query_runway(chain)));
+    }
+
+    let mut result = HashMap::new();
+    for handle in handles {
+        match handle.join().unwrap() {
+            Ok(runway) => result.insert(runway.chain.clone(), runway),
+            Err(error) => {
+                eprintln!("Error while querying runway: {:#?}", error);
+                continue;
+            }
+        };
+    }
+    result
+}
@@ -0,0 +1,181 @@
+use std::fs;
+
+use serde::Deserialize;
+
+use crate::helpers::{
+    time::parse_time,
+    volume::period_volume::{Pair, Protocol},
+};
+
+#[derive(Debug, Deserialize)]
+struct FeeModelConfig {
+    name: String,
+    share: f64,
+    #[serde(default)]
+    protocol_fee: bool,
+    #[serde(default)]
+    chains: Vec<String>,
+    #[serde(default)]
+    protocols: Vec<Protocol>,
+    #[serde(default)]
+    swap_fees: Vec<u32>,
+    from: Option<String>,
+    until: Option<String>,
+}
+
+/// Part of the swap fees going to the treasury for the pairs it matches.
+/// Empty filters match everything, `from` is inclusive and `until` exclusive.
+/// With `protocol_fee` the share set on the pool contract is used when it has one.
+/// Pools with a contract protocol fee are only matched by models using it or naming their protocol.
+#[derive(Debug, Clone)]
+pub struct FeeModel {
+    pub name: String,
+    pub share: f64,
+    protocol_fee: bool,
+    chains: Vec<String>,
+    protocols: Vec<Protocol>,
+    swap_fees: Vec<u32>, //in bps
+    from: Option<u64>,
+    until: Option<u64>,
+}
+
+impl FeeModel {
+    fn from_config(config: FeeModelConfig) -> Result<FeeModel, FeeModelError> {
+        if !(0.0..=1.0).contains(&config.share) {
+            return Err(FeeModelError::InvalidShare(config.name, config.share));
+        }
+        let parse_date = |date: Option<String>| match date {
+            Some(date) => match parse_time(&date) {
+                Some(timestamp) => Ok(Some(timestamp)),
+                None => Err(FeeModelError::InvalidDate(config.name.clone(), date)),
+            },
+            None => Ok(None),
+        };
+        let from = parse_date(config.from)?;
+        let until = parse_date(config.until)?;
+
+        Ok(FeeModel {
+            name: config.name,
+            share: config.share,
+            protocol_fee: config.protocol_fee,
+            chains: config.chains,
+            protocols: config.protocols,
+            swap_fees: config.swap_fees,
+            from,
+            until,
+        })
+    }
+
+    /// Model applying the same share to every pair, V3 pools included.
+    pub fn flat(name: String, share: f64) -> FeeModel {
+        FeeModel {
+            name,
+            share,
+            protocols: vec![Protocol::Legacy, Protocol::Trident, Protocol::V3],
+            ..FeeModel::default()
+        }
+    }
+
+    /// V3 pools carry their own protocol fee
+    pub fn v3_protocol_fee() -> FeeModel {
+        FeeModel {
+            name: "v3-protocol-fee".to_string(),
+            share: 0.0,
+            protocol_fee: true,
+            protocols: vec![Protocol::V3],
+            ..FeeModel::default()
+        }
+    }
+
+    pub fn share_of(&self, pair: &Pair) -> f64 {
+        match (self.protocol_fee, pair.protocol_fee) {
+            (true, Some(protocol_fee)) => protocol_fee,
+            _ => self.share,
+        }
+    }
+
+    fn applies(&self, chain: &str, pair: &Pair, timestamp: u64) -> bool {
+        (self.chains.is_empty() || self.chains.iter().any(|model_chain| model_chain == chain))
+            && (self.protocols.is_empty() || self.protocols.contains(&pair.protocol))
+            && (pair.protocol_fee.is_none()
+                || self.protocol_fee
+                || self.protocols.contains(&pair.protocol))
+            && (self.swap_fees.is_empty() || self.swap_fees.contains(&pair.swap_fee))
+            && self.from.is_none_or(|from| timestamp >= from)
+            && self.until.is_none_or(|until| timestamp < until)
+    }
+}
+
+impl Default for FeeModel {
+    //0.05% of the 0.30% swap fee goes to xSUSHI/treasury
+    fn default() -> FeeModel {
+        FeeModel {
+            name: "default-1/6".to_string(),
+            share: 1.0 / 6.0,
+            protocol_fee: false,
+            chains: vec![],
+            protocols: vec![],
+            swap_fees: vec![],
+            from: None,
+            until: None,
+        }
+    }
+}
+
+#[derive(Debug)]
+#[allow(dead_code)] //only read through Debug when printing errors
+pub enum FeeModelError {
+    ReadError(String),
+    ParsingError(String),
+    InvalidShare(String, f64),
+    InvalidDate(String, String),
+}
+
+/// Ordered fee models, the first one matching a pair applies.
+/// The V3 protocol fee and default models are always last so every pair gets one.
+#[derive(Debug, Clone)]
+pub struct FeeModels {
+    models: Vec<FeeModel>,
+}
+
+impl Default for FeeModels {
+    fn default() -> FeeModels {
+        FeeModels {
+            models: vec![FeeModel::v3_protocol_fee(), FeeModel::default()],
+        }
+    }
+}
+
+impl FeeModels {
+    pub fn flat(share: f64) -> FeeModels {
+        FeeModels {
+            models: vec![FeeModel::flat(format!("flat-{share}"), share)],
+        }
+    }
+
+    pub fn from_file(path: &str) -> Result<FeeModels, FeeModelError> {
+        let file = match fs::read_to_string(path) {
+            Ok(file) => file,
+            Err(error) => return Err(FeeModelError::ReadError(error.to_string())),
+        };
+        let configs: Vec<FeeModelConfig> = match serde_json::from_str(&file) {
+            Ok(configs) => configs,
+            Err(error) => return Err(FeeModelError::ParsingError(error.to_string())),
+        };
+
+        let mut models = vec![];
+        for config in configs {
+            models.push(FeeModel::from_config(config)?);
+        }
+        models.push(FeeModel::v3_protocol_fee());
+        models.push(FeeModel::default());
+        Ok(FeeModels { models })
+    }
+
+    pub fn model_for(&self, chain: &str, pair: &Pair, timestamp: u64) -> &FeeModel {
+        self.models
+            .iter()
+            .find(|model| model.applies(chain, pair, timestamp))
+            .unwrap() //default model matches everything
+    }
+}
@@ -1,4 +1,8 @@
 pub mod block;
+pub mod erc20;
 pub mod farm;
+pub mod fees;
+pub mod price;
+pub mod time;
 pub mod token_list;
 pub mod volume;
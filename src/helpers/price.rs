@@ -0,0 +1,31 @@
+use std::{collections::HashMap, error::Error};
+
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+struct Prices {
+    coins: HashMap<String, Price>,
+}
+
+#[derive(Deserialize)]
+#[allow(dead_code)] //mirrors the llama api response
+struct Price {
+    decimals: u32,
+    symbol: String,
+    price: f64,
+    timestamp: u32,
+    confidence: f64,
+}
+
+pub fn query_sushi_price() -> Result<f64, Box<dyn Error>> {
+    let url = "https://coins.llama.fi/prices/current/ethereum:0x6b3595068778dd592e39a122f4f5a5cf09c90fe2?searchWidth=4h";
+    let client = reqwest::blocking::Client::new();
+    let res = client.get(url).send()?;
+    let prices: Prices = res.json()?;
+    let price = prices
+        .coins
+        .get("ethereum:0x6b3595068778dd592e39a122f4f5a5cf09c90fe2")
+        .unwrap()
+        .price;
+    Ok(price)
+}
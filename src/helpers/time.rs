@@ -0,0 +1,55 @@
+use chrono::{DateTime, NaiveDate, NaiveDateTime, TimeZone, Utc};
+
+/// Parse a UTC time given as a unix timestamp, a RFC 3339 date
+/// or one of `YYYY-MM-DD`, `YYYY-MM-DD HH:MM`, `YYYY-MM-DD HH:MM:SS`.
+pub fn parse_time(time: &str) -> Option<u64> {
+    let time = time.trim().trim_end_matches(" UTC");
+    if let Ok(timestamp) = time.parse::<u64>() {
+        return Some(timestamp);
+    }
+    if let Ok(datetime) = DateTime::parse_from_rfc3339(time) {
+        return u64::try_from(datetime.timestamp()).ok();
+    }
+    for format in [
+        "%Y-%m-%d %H:%M:%S",
+        "%Y-%m-%d %H:%M",
+        "%Y-%m-%dT%H:%M:%S",
+        "%Y-%m-%dT%H:%M",
+    ] {
+        if let Ok(datetime) = NaiveDateTime::parse_from_str(time, format) {
+            return u64::try_from(datetime.timestamp()).ok();
+        }
+    }
+    match NaiveDate::parse_from_str(time, "%Y-%m-%d") {
+        Ok(date) => u64::try_from(date.and_hms_opt(0, 0, 0)?.timestamp()).ok(),
+        Err(_) => None,
+    }
+}
+
+pub fn format_timestamp(timestamp: u64) -> String {
+    match Utc.timestamp_opt(timestamp as i64, 0).single() {
+        Some(datetime) => datetime.format("%Y-%m-%d %H:%M:%S UTC").to_string(),
+        None => timestamp.to_string(),
+    }
+}
+
+pub fn format_date(timestamp: u64) -> String {
+    match Utc.timestamp_opt(timestamp as i64, 0).single() {
+        Some(datetime) => datetime.format("%Y-%m-%d").to_string(),
+        None => timestamp.to_string(),
+    }
+}
+
+/// Signed duration formatted as `+1h02m03s` / `-45s`.
+pub fn format_delta(delta: i64) -> String {
+    let sign = if delta < 0 { "-" } else { "+" };
+    let delta = delta.unsigned_abs();
+    let (hours, minutes, seconds) = (delta / 3_600, delta % 3_600 / 60, delta % 60);
+    if hours > 0 {
+        format!("{sign}{hours}h{minutes:02}m{seconds:02}s")
+    } else if minutes > 0 {
+        format!("{sign}{minutes}m{seconds:02}s")
+    } else {
+        format!("{sign}{seconds}s")
+    }
+}
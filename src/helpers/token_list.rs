@@ -1,5 +1,76 @@
-use crate::network;
-use std::error::Error;
+use crate::{helpers::volume::period_volume::Pair, network};
+use serde::Deserialize;
+use std::{collections::HashMap, error::Error, fs};
+
+//only the core tokens (wrapped native, stables, SUSHI) of the main chains, a few per chain
+//against hundreds in the remote list: pairs of long tail tokens are left out, so volume and
+//revenues filtered with it are far below the remote list ones
+const SNAPSHOT: &str = include_str!("token_list_snapshot.json");
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum TokenListSource {
+    #[default]
+    Remote, //helper.sushibackup.com
+    File(String), //Uniswap format tokenlist.json
+    Snapshot,     //bundled with the binary
+    None,         //no filter, every pair
+}
+
+/// Where to read the token list used to filter pairs, with per-chain tokens to add or drop.
+#[derive(Debug, Clone, Default)]
+pub struct TokenListConfig {
+    pub source: TokenListSource,
+    pub allow: Vec<(String, String)>, //chain, token address
+    pub deny: Vec<(String, String)>,
+}
+
+impl TokenListConfig {
+    /// No filter at all, every pair is kept.
+    pub fn all_pairs() -> TokenListConfig {
+        TokenListConfig {
+            source: TokenListSource::None,
+            ..Default::default()
+        }
+    }
+
+    pub fn is_denied(&self, chain: &str, token: &str) -> bool {
+        self.deny
+            .iter()
+            .any(|(deny_chain, deny_token)| deny_chain == chain && deny_token == token)
+    }
+
+    /// True if a token of the pair is denied, also filters pairs when there's no token list.
+    pub fn denies(&self, chain: &str, pair: &Pair) -> bool {
+        self.is_denied(chain, &pair.token0.id) || self.is_denied(chain, &pair.token1.id)
+    }
+}
+
+/// Token list resolved for a chain, tokens being None when pairs aren't filtered.
+#[derive(Debug, Clone)]
+pub struct TokenList {
+    pub source: TokenListSource,
+    pub tokens: Option<Vec<String>>,
+    pub fallback: bool, //true if the snapshot, or no filter, replaced an unreachable remote list
+}
+
+impl TokenList {
+    pub fn describe(&self) -> String {
+        let source = match &self.source {
+            TokenListSource::Remote => "remote helper".to_string(),
+            TokenListSource::File(path) => path.clone(),
+            TokenListSource::Snapshot if self.fallback => {
+                "bundled snapshot (remote unreachable)".to_string()
+            }
+            TokenListSource::Snapshot => "bundled snapshot".to_string(),
+            TokenListSource::None if self.fallback => "none (remote unreachable)".to_string(),
+            TokenListSource::None => "none".to_string(),
+        };
+        match &self.tokens {
+            Some(tokens) => format!("{source}, {} tokens", tokens.len()),
+            None => format!("{source}, every pair"),
+        }
+    }
+}
 
 pub fn query_token_list(chain: &str) -> Result<Vec<String>, Box<dyn Error>> {
     let chain_id = match network::NETWORKS.get(chain) {
@@ -18,3 +89,101 @@ pub fn query_token_list(chain: &str) -> Result<Vec<String>, Box<dyn Error>> {
         .collect();
     Ok(token_list)
 }
+
+#[derive(Deserialize)]
+struct UniswapTokenList {
+    tokens: Vec<UniswapToken>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct UniswapToken {
+    chain_id: u32,
+    address: String,
+}
+
+/// Tokens of a chain from a Uniswap format token list file.
+pub fn read_token_list_file(chain: &str, path: &str) -> Result<Vec<String>, Box<dyn Error>> {
+    let chain_id = match network::NETWORKS.get(chain) {
+        Some(network) => network.chain_id,
+        None => return Err(format!("no chain id for {chain}").into()),
+    };
+    let token_list: UniswapTokenList = serde_json::from_str(&fs::read_to_string(path)?)?;
+    Ok(token_list
+        .tokens
+        .into_iter()
+        .filter(|token| token.chain_id == chain_id)
+        .map(|token| token.address.to_lowercase())
+        .collect())
+}
+
+pub fn snapshot_token_list(chain: &str) -> Result<Vec<String>, Box<dyn Error>> {
+    let mut snapshot: HashMap<String, Vec<String>> = serde_json::from_str(SNAPSHOT)?;
+    match snapshot.remove(chain) {
+        Some(tokens) => Ok(tokens),
+        None => Err(format!("no bundled token list for {chain}").into()),
+    }
+}
+
+/// Token list of a chain from the configured source, allow/deny lists applied.
+/// An unreachable remote list falls back to the snapshot instead of dropping the filter,
+/// pairs are only left unfiltered when the snapshot doesn't have the chain either.
+pub fn resolve_token_list(
+    chain: &str,
+    config: &TokenListConfig,
+) -> Result<TokenList, Box<dyn Error>> {
+    let (source, tokens, fallback) = match &config.source {
+        TokenListSource::None => {
+            return Ok(TokenList {
+                source: TokenListSource::None,
+                tokens: None,
+                fallback: false,
+            })
+        }
+        TokenListSource::Remote => match query_token_list(chain) {
+            Ok(tokens) => (TokenListSource::Remote, tokens, false),
+            Err(error) => {
+                eprintln!(
+                    "Error while querying token list, using snapshot: {:#?}",
+                    error
+                );
+                match snapshot_token_list(chain) {
+                    Ok(tokens) => (TokenListSource::Snapshot, tokens, true),
+                    Err(error) => {
+                        eprintln!("{error}, pairs of {chain} aren't filtered");
+                        return Ok(TokenList {
+                            source: TokenListSource::None,
+                            tokens: None,
+                            fallback: true,
+                        });
+                    }
+                }
+            }
+        },
+        TokenListSource::File(path) => (
+            config.source.clone(),
+            read_token_list_file(chain, path)?,
+            false,
+        ),
+        TokenListSource::Snapshot => (
+            TokenListSource::Snapshot,
+            snapshot_token_list(chain)?,
+            false,
+        ),
+    };
+
+    let mut tokens: Vec<String> = tokens
+        .into_iter()
+        .filter(|token| !config.is_denied(chain, token))
+        .collect();
+    for (allow_chain, token) in config.allow.iter() {
+        if allow_chain == chain && !tokens.contains(token) {
+            tokens.push(token.clone());
+        }
+    }
+    Ok(TokenList {
+        source,
+        tokens: Some(tokens),
+        fallback,
+    })
+}
@@ -0,0 +1,274 @@
+use std::{
+    collections::HashMap,
+    thread::{self, JoinHandle},
+};
+
+use graphql_client::{GraphQLQuery, Response};
+
+use crate::{
+    graphql::queries::{
+        largestSwapsQuery::{largest_swaps_query, LargestSwapsQuery},
+        pairsDaySnapshotsQuery::{pairs_day_snapshots_query, PairsDaySnapshotsQuery},
+    },
+    helpers::volume::period_volume::{Pair, Protocol},
+    network::LEGACY_SUBGRAPH,
+    subgraph,
+};
+
+const HISTORY_DAYS: u64 = 30; //max days before the period the volume is compared with
+const MAX_SNAPSHOT_PAGES: i64 = 5;
+const TOP_TRADERS: usize = 3;
+
+#[derive(Debug, Clone, Copy)]
+pub enum AnomalyFlag {
+    VolumeLiquidity(f64),   //daily volume over liquidity
+    NegativeDiff,           //cumulative volume or fees went down over the period
+    VolumeJump(f64),        //daily volume over the daily volume of the previous days
+    ConcentratedSwaps(f64), //share of the volume swapped to the top traders
+}
+
+impl AnomalyFlag {
+    pub fn describe(&self) -> String {
+        match self {
+            AnomalyFlag::VolumeLiquidity(ratio) => format!("volume {ratio:.0}x liquidity/day"),
+            AnomalyFlag::NegativeDiff => "negative volume or fees".to_string(),
+            AnomalyFlag::VolumeJump(jump) => format!("volume {jump:.0}x previous days"),
+            AnomalyFlag::ConcentratedSwaps(share) => {
+                format!("{:.0}% swapped to {TOP_TRADERS} addresses", share * 100.0)
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct AnomalyThresholds {
+    pub max_volume_liquidity: f64,
+    pub max_volume_jump: f64,
+    pub max_trader_share: f64,
+    pub deep_checks: usize, //pairs with the most volume checked against history and swaps
+}
+
+#[derive(Debug, Clone)]
+pub struct PairAnomaly {
+    pub pair: Pair,
+    pub flags: Vec<AnomalyFlag>,
+}
+
+#[derive(Debug)]
+#[allow(dead_code)] //only read through Debug when printing errors
+pub enum AnomalyQueryError {
+    RequestError(String, String),
+    EmptyResponse(String),
+    EmptyPeriod(String, u64, u64), //daily ratios need the period end after its start
+}
+
+//daily volume of the pairs from their day snapshots since a timestamp
+fn query_daily_volumes(
+    chain: &str,
+    subgraph: &str,
+    ids: Vec<String>,
+    from: u64,
+) -> Result<HashMap<String, Vec<(u64, f64)>>, AnomalyQueryError> {
+    let mut volumes: HashMap<String, Vec<(u64, f64)>> = HashMap::new();
+    for page in 0..MAX_SNAPSHOT_PAGES {
+        let request_body =
+            PairsDaySnapshotsQuery::build_query(pairs_day_snapshots_query::Variables {
+                pairs: ids.clone(),
+                from: from as i64,
+                skip: page * 1000,
+            });
+        let res: Response<pairs_day_snapshots_query::ResponseData> =
+            match subgraph::query_subgraph(subgraph, &request_body) {
+                Ok(res) => res,
+                Err(error) => {
+                    return Err(AnomalyQueryError::RequestError(
+                        chain.to_string(),
+                        error.to_string(),
+                    ))
+                }
+            };
+        let snapshots = match res.data {
+            Some(data) => data.pair_day_snapshots,
+            None => return Err(AnomalyQueryError::EmptyResponse(chain.to_string())),
+        };
+        let count = snapshots.len();
+        for snapshot in snapshots {
+            if let Ok(volume) = snapshot.volume_usd.parse::<f64>() {
+                volumes
+                    .entry(snapshot.pair.id)
+                    .or_default()
+                    .push((snapshot.date as u64, volume));
+            }
+        }
+        if count < 1000 {
+            break;
+        }
+    }
+    Ok(volumes)
+}
+
+//share of the period volume of a pair swapped to its top traders, from its largest swaps
+fn query_trader_share(
+    chain: &str,
+    subgraph: &str,
+    pair: &Pair,
+    (from, until): (u64, u64),
+) -> Result<f64, AnomalyQueryError> {
+    let request_body = LargestSwapsQuery::build_query(largest_swaps_query::Variables {
+        pair: pair.id.clone(),
+        from: from.to_string(),
+        until: until.to_string(),
+    });
+    let res: Response<largest_swaps_query::ResponseData> =
+        match subgraph::query_subgraph(subgraph, &request_body) {
+            Ok(res) => res,
+            Err(error) => {
+                return Err(AnomalyQueryError::RequestError(
+                    chain.to_string(),
+                    error.to_string(),
+                ))
+            }
+        };
+    let swaps = match res.data {
+        Some(data) => data.swaps,
+        None => return Err(AnomalyQueryError::EmptyResponse(chain.to_string())),
+    };
+
+    let mut traders: HashMap<String, f64> = HashMap::new();
+    for swap in swaps {
+        *traders.entry(swap.to).or_default() += swap.amount_usd.parse::<f64>().unwrap_or(0.0);
+    }
+    let mut amounts: Vec<f64> = traders.into_values().collect();
+    amounts.sort_by(|a, b| b.total_cmp(a));
+    let top_amount: f64 = amounts.iter().take(TOP_TRADERS).sum();
+    match pair.volume_usd {
+        volume if volume > 0.0 => Ok((top_amount / volume).min(1.0)),
+        _ => Ok(0.0),
+    }
+}
+
+/// Flag the pairs of a chain whose period volume looks wash traded or broken.
+/// Volume/liquidity and negative diffs are checked on every pair, history jumps and
+/// swap concentration only on the `deep_checks` pairs with the most volume (legacy/trident).
+pub fn detect_anomalies(
+    chain: String,
+    pairs: Vec<Pair>,
+    (start, end): (u64, u64),
+    thresholds: AnomalyThresholds,
+) -> Result<Vec<PairAnomaly>, AnomalyQueryError> {
+    if end <= start {
+        return Err(AnomalyQueryError::EmptyPeriod(chain, start, end));
+    }
+    let days = (end - start) as f64 / 86_400.0;
+    let mut flags: HashMap<String, Vec<AnomalyFlag>> = HashMap::new();
+    for pair in pairs.iter() {
+        let pair_flags = flags.entry(pair.id.clone()).or_default();
+        if pair.volume_usd < 0.0 || pair.fees_usd < 0.0 {
+            pair_flags.push(AnomalyFlag::NegativeDiff);
+        }
+        let daily_volume = pair.volume_usd / days;
+        let ratio = match pair.liquidity_usd {
+            liquidity if liquidity > 0.0 => daily_volume / liquidity,
+            _ if daily_volume > 0.0 => f64::INFINITY,
+            _ => 0.0,
+        };
+        if ratio > thresholds.max_volume_liquidity {
+            pair_flags.push(AnomalyFlag::VolumeLiquidity(ratio));
+        }
+    }
+
+    if let Some(subgraph) = LEGACY_SUBGRAPH.get(chain.as_str()) {
+        let mut top: Vec<&Pair> = pairs
+            .iter()
+            .filter(|pair| pair.protocol != Protocol::V3 && pair.volume_usd > 0.0)
+            .collect();
+        top.sort_by(|a, b| b.volume_usd.total_cmp(&a.volume_usd));
+        top.truncate(thresholds.deep_checks);
+
+        let history_start = start.saturating_sub(HISTORY_DAYS * 86_400);
+        let ids: Vec<String> = top.iter().map(|pair| pair.id.clone()).collect();
+        let daily_volumes = query_daily_volumes(&chain, subgraph, ids, history_start)?;
+        for pair in top {
+            let history: Vec<(u64, f64)> = daily_volumes
+                .get(&pair.id)
+                .map(|days| {
+                    days.iter()
+                        .filter(|(date, _)| *date < start)
+                        .copied()
+                        .collect()
+                })
+                .unwrap_or_default();
+            //averaged over the days since the first snapshot, young pairs have less history
+            if let Some(first_date) = history.iter().map(|(date, _)| *date).min() {
+                let history_days = start.saturating_sub(first_date).div_ceil(86_400).max(1);
+                let history_volume = history.iter().map(|(_, volume)| volume).sum::<f64>()
+                    / history_days.min(HISTORY_DAYS) as f64;
+                let jump = match history_volume {
+                    volume if volume > 0.0 => pair.volume_usd / days / volume,
+                    _ => f64::INFINITY,
+                };
+                if jump > thresholds.max_volume_jump {
+                    flags
+                        .entry(pair.id.clone())
+                        .or_default()
+                        .push(AnomalyFlag::VolumeJump(jump));
+                }
+            }
+
+            let share = match query_trader_share(&chain, subgraph, pair, (start, end)) {
+                Ok(share) => share,
+                Err(error) => {
+                    eprintln!(
+                        "Error while querying {} largest swaps, skipped: {:#?}",
+                        pair.name, error
+                    );
+                    continue;
+                }
+            };
+            if share > thresholds.max_trader_share {
+                flags
+                    .entry(pair.id.clone())
+                    .or_default()
+                    .push(AnomalyFlag::ConcentratedSwaps(share));
+            }
+        }
+    }
+
+    Ok(pairs
+        .into_iter()
+        .filter_map(|pair| match flags.remove(&pair.id) {
+            Some(flags) if !flags.is_empty() => Some(PairAnomaly { pair, flags }),
+            _ => None,
+        })
+        .collect())
+}
+
+type ChainAnomaliesHandle = JoinHandle<Result<(String, Vec<PairAnomaly>), AnomalyQueryError>>;
+
+/// Anomalies of several chains, each given with its pairs and period start and end timestamps.
+pub fn detect_anomalies_multichain(
+    chains: Vec<(String, Vec<Pair>, (u64, u64))>,
+    thresholds: AnomalyThresholds,
+) -> HashMap<String, Vec<PairAnomaly>> {
+    let mut handles: Vec<ChainAnomaliesHandle> = vec![];
+    for (chain, pairs, period) in chains {
+        handles.push(thread::spawn(move || {
+            match detect_anomalies(chain.clone(), pairs, period, thresholds) {
+                Ok(anomalies) => Ok((chain, anomalies)),
+                Err(error) => Err(error),
+            }
+        }));
+    }
+
+    let mut result: HashMap<String, Vec<PairAnomaly>> = HashMap::new();
+    for handle in handles {
+        match handle.join().unwrap() {
+            Ok((chain, anomalies)) => result.insert(chain, anomalies),
+            Err(error) => {
+                eprintln!("Error while detecting anomalies: {:#?}", error);
+                continue;
+            }
+        };
+    }
+    result
+}
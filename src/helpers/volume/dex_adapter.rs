@@ -0,0 +1,269 @@
+use std::collections::HashMap;
+
+use graphql_client::{GraphQLQuery, Response};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    graphql::queries::subgraphHeadQuery::{subgraph_head_query, SubgraphHeadQuery},
+    helpers::block::block_timestamp::Block,
+    network::{
+        LEGACY_SUBGRAPH, PANCAKESWAP_SUBGRAPH, QUICKSWAP_SUBGRAPH, SPOOKYSWAP_SUBGRAPH,
+        UNISWAP_V2_SUBGRAPH,
+    },
+    subgraph,
+};
+
+pub const SUSHISWAP: &str = "sushiswap";
+
+/// Names of the factory entity and of its cumulative fields in a v2 fork subgraph schema.
+#[derive(Debug, Clone, Deserialize)]
+pub struct V2Schema {
+    pub factory: String,
+    pub volume_usd: String,
+    pub liquidity_usd: String,
+    pub tx_count: String,
+}
+
+impl V2Schema {
+    fn from(factory: &str, volume_usd: &str, liquidity_usd: &str, tx_count: &str) -> V2Schema {
+        V2Schema {
+            factory: factory.to_string(),
+            volume_usd: volume_usd.to_string(),
+            liquidity_usd: liquidity_usd.to_string(),
+            tx_count: tx_count.to_string(),
+        }
+    }
+
+    pub fn sushiswap() -> V2Schema {
+        V2Schema::from("factories", "volumeUSD", "liquidityUSD", "transactionCount")
+    }
+
+    pub fn uniswap_v2() -> V2Schema {
+        V2Schema::from(
+            "uniswapFactories",
+            "totalVolumeUSD",
+            "totalLiquidityUSD",
+            "txCount",
+        )
+    }
+
+    pub fn pancakeswap() -> V2Schema {
+        V2Schema::from(
+            "pancakeFactories",
+            "totalVolumeUSD",
+            "totalLiquidityUSD",
+            "totalTransactions",
+        )
+    }
+
+    //factory state at both blocks, aliased to the same fields whatever the schema
+    fn factories_query(&self) -> String {
+        let factories = |block: &str| {
+            format!(
+                "  {block}: {}(first: 10, block: {{number: ${block}}}) {{\n    volumeUSD: {}\n    liquidityUSD: {}\n    txCount: {}\n  }}\n",
+                self.factory, self.volume_usd, self.liquidity_usd, self.tx_count
+            )
+        };
+        format!(
+            "query DexFactoriesQuery($start: Int!, $end: Int!) {{\n{}{}}}",
+            factories("start"),
+            factories("end")
+        )
+    }
+}
+
+/// A v2 fork whose subgraphs share a schema, Sushi included.
+#[derive(Debug, Clone, Deserialize)]
+pub struct DexAdapter {
+    pub name: String,
+    pub schema: V2Schema,
+    pub subgraphs: HashMap<String, String>, //by chain
+}
+
+impl DexAdapter {
+    fn from(name: &str, schema: V2Schema, subgraphs: &phf::Map<&str, &str>) -> DexAdapter {
+        DexAdapter {
+            name: name.to_string(),
+            schema,
+            subgraphs: subgraphs
+                .entries()
+                .map(|(chain, url)| (chain.to_string(), url.to_string()))
+                .collect(),
+        }
+    }
+
+    pub fn builtin() -> Vec<DexAdapter> {
+        vec![
+            DexAdapter::from(SUSHISWAP, V2Schema::sushiswap(), &LEGACY_SUBGRAPH),
+            DexAdapter::from("uniswap", V2Schema::uniswap_v2(), &UNISWAP_V2_SUBGRAPH),
+            DexAdapter::from(
+                "pancakeswap",
+                V2Schema::pancakeswap(),
+                &PANCAKESWAP_SUBGRAPH,
+            ),
+            DexAdapter::from("quickswap", V2Schema::uniswap_v2(), &QUICKSWAP_SUBGRAPH),
+            DexAdapter::from("spookyswap", V2Schema::uniswap_v2(), &SPOOKYSWAP_SUBGRAPH),
+        ]
+    }
+}
+
+/// Volume and transactions of a dex on a chain over the period, liquidity at its end.
+#[derive(Debug, Clone)]
+pub struct DexVolume {
+    pub dex: String,
+    pub volume_usd: f64,
+    pub liquidity_usd: f64,
+    pub tx_count: u64,
+}
+
+#[derive(Debug)]
+#[allow(dead_code)] //only read through Debug when printing errors
+pub enum DexQueryError {
+    UnknownChain(String, String),
+    RequestError(String, String, String),
+    EmptyResponse(String, String),
+    MissingTimestamp(String, String),
+    ParsingFactory(String, String),
+}
+
+#[derive(Serialize)]
+struct FactoriesVariables {
+    start: i64,
+    end: i64,
+}
+
+#[derive(Serialize)]
+struct FactoriesQueryBody {
+    variables: FactoriesVariables,
+    query: String,
+    #[serde(rename = "operationName")]
+    operation_name: &'static str,
+}
+
+#[derive(Deserialize, Debug)]
+struct FactoriesResponse {
+    start: Vec<FactoryState>,
+    end: Vec<FactoryState>,
+}
+
+#[derive(Deserialize, Debug)]
+struct FactoryState {
+    #[serde(rename = "volumeUSD")]
+    volume_usd: String,
+    #[serde(rename = "liquidityUSD")]
+    liquidity_usd: String,
+    #[serde(rename = "txCount")]
+    tx_count: String,
+}
+
+//volume, liquidity and transactions summed over the factories, None if unparsable
+fn sum_factories(factories: &[FactoryState]) -> Option<(f64, f64, u64)> {
+    let mut sum = (0.0, 0.0, 0);
+    for factory in factories {
+        sum.0 += factory.volume_usd.parse::<f64>().ok()?;
+        sum.1 += factory.liquidity_usd.parse::<f64>().ok()?;
+        sum.2 += factory.tx_count.parse::<u64>().ok()?;
+    }
+    Some(sum)
+}
+
+impl DexAdapter {
+    fn subgraph(&self, chain: &str) -> Result<&str, DexQueryError> {
+        match self.subgraphs.get(chain) {
+            Some(subgraph) => Ok(subgraph),
+            None => Err(DexQueryError::UnknownChain(
+                self.name.clone(),
+                chain.to_string(),
+            )),
+        }
+    }
+
+    /// Last block indexed by the dex subgraph of a chain.
+    pub fn query_head(&self, chain: &str) -> Result<Block, DexQueryError> {
+        let request_body = SubgraphHeadQuery::build_query(subgraph_head_query::Variables);
+        let res: Response<subgraph_head_query::ResponseData> =
+            match subgraph::query_subgraph(self.subgraph(chain)?, &request_body) {
+                Ok(res) => res,
+                Err(error) => {
+                    return Err(DexQueryError::RequestError(
+                        self.name.clone(),
+                        chain.to_string(),
+                        error.to_string(),
+                    ))
+                }
+            };
+        let meta = match res.data.and_then(|data| data.meta) {
+            Some(meta) => meta,
+            None => {
+                return Err(DexQueryError::EmptyResponse(
+                    self.name.clone(),
+                    chain.to_string(),
+                ))
+            }
+        };
+        //the period start is resolved from the head time, can't default it
+        match meta.block.timestamp {
+            Some(timestamp) => Ok(Block {
+                number: meta.block.number as u64,
+                timestamp: timestamp as u64,
+            }),
+            None => Err(DexQueryError::MissingTimestamp(
+                self.name.clone(),
+                chain.to_string(),
+            )),
+        }
+    }
+
+    /// Period volume of the dex on a chain from its factories cumulative volume, a factory
+    /// missing at the start block counts from 0.
+    pub fn query_period_volume(
+        &self,
+        chain: &str,
+        (start_block, end_block): (u64, u64),
+    ) -> Result<DexVolume, DexQueryError> {
+        let request_body = FactoriesQueryBody {
+            variables: FactoriesVariables {
+                start: start_block as i64,
+                end: end_block as i64,
+            },
+            query: self.schema.factories_query(),
+            operation_name: "DexFactoriesQuery",
+        };
+        let res: Response<FactoriesResponse> =
+            match subgraph::query_subgraph(self.subgraph(chain)?, &request_body) {
+                Ok(res) => res,
+                Err(error) => {
+                    return Err(DexQueryError::RequestError(
+                        self.name.clone(),
+                        chain.to_string(),
+                        error.to_string(),
+                    ))
+                }
+            };
+        let factories = match res.data {
+            Some(data) => data,
+            None => {
+                return Err(DexQueryError::EmptyResponse(
+                    self.name.clone(),
+                    chain.to_string(),
+                ))
+            }
+        };
+
+        match (
+            sum_factories(&factories.start),
+            sum_factories(&factories.end),
+        ) {
+            (Some(start), Some(end)) => Ok(DexVolume {
+                dex: self.name.clone(),
+                volume_usd: end.0 - start.0,
+                liquidity_usd: end.1,
+                tx_count: end.2.saturating_sub(start.2),
+            }),
+            _ => Err(DexQueryError::ParsingFactory(
+                self.name.clone(),
+                chain.to_string(),
+            )),
+        }
+    }
+}
@@ -0,0 +1,115 @@
+use std::thread::{self, JoinHandle};
+
+use crate::helpers::{
+    block::block_timestamp::{query_block_at, BlockQueryError, BlockSource},
+    volume::dex_adapter::{DexAdapter, DexVolume, SUSHISWAP},
+};
+
+/// Period volume of every dex with a subgraph on a chain, between the same blocks.
+#[derive(Debug)]
+pub struct ChainMarket {
+    pub chain: String,
+    pub start_block: u64,
+    pub end_block: u64,
+    pub dexes: Vec<DexVolume>,
+}
+
+impl ChainMarket {
+    pub fn volume_usd(&self) -> f64 {
+        self.dexes.iter().map(|dex| dex.volume_usd).sum()
+    }
+
+    pub fn liquidity_usd(&self) -> f64 {
+        self.dexes.iter().map(|dex| dex.liquidity_usd).sum()
+    }
+
+    pub fn sushi(&self) -> Option<&DexVolume> {
+        self.dexes.iter().find(|dex| dex.dex == SUSHISWAP)
+    }
+}
+
+#[derive(Debug)]
+#[allow(dead_code)] //only read through Debug when printing errors
+pub enum MarketShareError {
+    NoDex(String),
+    Block(String, BlockQueryError),
+}
+
+/// Volume of the dexes of a chain over the last days. The period ends at the lowest head of
+/// their subgraphs so every dex is compared on the same blocks, dexes failing are left out.
+pub fn query_chain_market(
+    chain: String,
+    adapters: &[DexAdapter],
+    days: u32,
+    block_source: BlockSource,
+) -> Result<ChainMarket, MarketShareError> {
+    let mut heads = vec![];
+    for adapter in adapters
+        .iter()
+        .filter(|adapter| adapter.subgraphs.contains_key(&chain))
+    {
+        match adapter.query_head(&chain) {
+            Ok(head) => heads.push((adapter, head)),
+            Err(error) => eprintln!("Error while querying dex head: {:#?}", error),
+        }
+    }
+    let end = match heads
+        .iter()
+        .map(|(_, head)| *head)
+        .min_by_key(|head| head.number)
+    {
+        Some(end) => end,
+        None => return Err(MarketShareError::NoDex(chain)),
+    };
+    let start = match query_block_at(
+        &chain,
+        end.timestamp.saturating_sub(u64::from(86_400 * days)),
+        block_source,
+    ) {
+        Ok(start) => start,
+        Err(error) => return Err(MarketShareError::Block(chain, error)),
+    };
+
+    let mut dexes = vec![];
+    for (adapter, _) in heads {
+        match adapter.query_period_volume(&chain, (start.number, end.number)) {
+            Ok(volume) => dexes.push(volume),
+            Err(error) => eprintln!("Error while querying dex volume: {:#?}", error),
+        }
+    }
+    Ok(ChainMarket {
+        chain,
+        start_block: start.number,
+        end_block: end.number,
+        dexes,
+    })
+}
+
+type ChainMarketHandle = JoinHandle<Result<ChainMarket, MarketShareError>>;
+
+pub fn query_market_multichain(
+    chains: Vec<String>,
+    adapters: &[DexAdapter],
+    days: u32,
+    block_source: BlockSource,
+) -> Vec<ChainMarket> {
+    let mut handles: Vec<ChainMarketHandle> = vec![];
+    for chain in chains {
+        let adapters = adapters.to_vec();
+        handles.push(thread::spawn(move || {
+            query_chain_market(chain, &adapters, days, block_source)
+        }));
+    }
+
+    let mut result: Vec<ChainMarket> = vec![];
+    for handle in handles {
+        match handle.join().unwrap() {
+            Ok(market) => result.push(market),
+            Err(error) => {
+                eprintln!("Error while querying market share: {:#?}", error);
+                continue;
+            }
+        };
+    }
+    result
+}
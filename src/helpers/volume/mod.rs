@@ -1 +1,9 @@
+pub mod anomaly;
+pub mod dex_adapter;
+pub mod market_share;
+pub mod pair_detail;
+pub mod pairs_state;
 pub mod period_volume;
+pub mod snapshots;
+pub mod swaps;
+pub mod v3_volume;
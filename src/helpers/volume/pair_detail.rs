@@ -0,0 +1,233 @@
+use graphql_client::{GraphQLQuery, Response};
+
+use crate::{
+    graphql::queries::{
+        liquidityPositionsQuery::{liquidity_positions_query, LiquidityPositionsQuery},
+        pairDetailQuery::{pair_detail_query, PairDetailQuery},
+        pairsBySymbolsQuery::{pairs_by_symbols_query, PairsBySymbolsQuery},
+    },
+    helpers::volume::period_volume::{Protocol, Token},
+    network::LEGACY_SUBGRAPH,
+    subgraph,
+};
+
+const MAX_POSITION_PAGES: usize = 20; //20k LP holders
+
+#[derive(Debug, Clone)]
+pub struct PairDay {
+    pub date: u64,
+    pub volume_usd: f64,
+    pub fees_usd: f64,
+    pub liquidity_usd: f64,
+    pub tx_count: u64,
+}
+
+/// Current state of a pair with its last daily snapshots, most recent first.
+#[derive(Debug, Clone)]
+pub struct PairDetail {
+    pub id: String,
+    pub name: String,
+    pub token0: Token,
+    pub token1: Token,
+    pub protocol: Protocol,
+    pub swap_fee: u32, //in bps
+    pub reserve0: f64,
+    pub reserve1: f64,
+    pub total_supply: f64, //LP tokens, in wei
+    pub liquidity_usd: f64,
+    pub token0_price: f64, //token0 in token1
+    pub token1_price: f64, //token1 in token0
+    pub volume_usd: f64,
+    pub fees_usd: f64,
+    pub tx_count: u64,
+    pub created_at: u64,
+    pub days: Vec<PairDay>,
+}
+
+//reserves are raw token amounts
+fn token_amount(amount: &str, decimals: &str) -> Option<f64> {
+    let decimals: i32 = decimals.parse().ok()?;
+    Some(amount.parse::<f64>().ok()? / 10f64.powi(decimals))
+}
+
+impl PairDetail {
+    fn from(data: pair_detail_query::PairDetailQueryPair) -> Option<PairDetail> {
+        let mut days: Vec<PairDay> = vec![];
+        for day in data.day_snapshots {
+            days.push(PairDay {
+                date: day.date as u64,
+                volume_usd: day.volume_usd.parse().ok()?,
+                fees_usd: day.fees_usd.parse().ok()?,
+                liquidity_usd: day.liquidity_usd.parse().ok()?,
+                tx_count: day.transaction_count.parse().ok()?,
+            });
+        }
+        Some(PairDetail {
+            reserve0: token_amount(&data.reserve0, &data.token0.decimals)?,
+            reserve1: token_amount(&data.reserve1, &data.token1.decimals)?,
+            id: data.id,
+            name: data.name,
+            token0: Token {
+                id: data.token0.id,
+                symbol: data.token0.symbol,
+            },
+            token1: Token {
+                id: data.token1.id,
+                symbol: data.token1.symbol,
+            },
+            protocol: Protocol::from_source(&data.source),
+            swap_fee: data.swap_fee.parse().ok()?,
+            total_supply: data.liquidity.parse().ok()?,
+            liquidity_usd: data.liquidity_usd.parse().ok()?,
+            token0_price: data.token0_price.parse().ok()?,
+            token1_price: data.token1_price.parse().ok()?,
+            volume_usd: data.volume_usd.parse().ok()?,
+            fees_usd: data.fees_usd.parse().ok()?,
+            tx_count: data.tx_count.parse().ok()?,
+            created_at: data.created_at_timestamp.parse().ok()?,
+            days,
+        })
+    }
+}
+
+#[derive(Debug)]
+#[allow(dead_code)] //only read through Debug when printing errors
+pub enum PairDetailQueryError {
+    UnknownChain(String),
+    RequestError(String, String),
+    EmptyResponse(String),
+    PairNotFound(String, String),
+    ParsingPair(String, String),
+}
+
+fn legacy_subgraph(chain: &str) -> Result<&'static str, PairDetailQueryError> {
+    match LEGACY_SUBGRAPH.get(chain) {
+        Some(subgraph) => Ok(subgraph),
+        None => Err(PairDetailQueryError::UnknownChain(chain.to_string())),
+    }
+}
+
+/// Pair details with up to `days` daily snapshots.
+pub fn query_pair_detail(
+    chain: &str,
+    id: &str,
+    days: u32,
+) -> Result<PairDetail, PairDetailQueryError> {
+    let subgraph = legacy_subgraph(chain)?;
+    let request_body = PairDetailQuery::build_query(pair_detail_query::Variables {
+        id: id.to_lowercase(),
+        days: days as i64,
+    });
+    let res: Response<pair_detail_query::ResponseData> =
+        match subgraph::query_subgraph(subgraph, &request_body) {
+            Ok(res) => res,
+            Err(error) => {
+                return Err(PairDetailQueryError::RequestError(
+                    chain.to_string(),
+                    error.to_string(),
+                ))
+            }
+        };
+    let pair = match res.data {
+        Some(data) => data.pair,
+        None => return Err(PairDetailQueryError::EmptyResponse(chain.to_string())),
+    };
+    match pair {
+        Some(pair) => match PairDetail::from(pair) {
+            Some(pair) => Ok(pair),
+            None => Err(PairDetailQueryError::ParsingPair(
+                chain.to_string(),
+                id.to_string(),
+            )),
+        },
+        None => Err(PairDetailQueryError::PairNotFound(
+            chain.to_string(),
+            id.to_string(),
+        )),
+    }
+}
+
+/// Pairs made of two token symbols (case insensitive, any order) as (id, name, liquidity USD),
+/// most liquid first.
+pub fn find_pairs_by_symbols(
+    chain: &str,
+    symbol0: &str,
+    symbol1: &str,
+) -> Result<Vec<(String, String, f64)>, PairDetailQueryError> {
+    let subgraph = legacy_subgraph(chain)?;
+    //the subgraph filter is case sensitive, try the symbols as given and uppercased
+    let mut symbols: Vec<String> = vec![];
+    for symbol in [symbol0, symbol1] {
+        for symbol in [symbol.to_string(), symbol.to_uppercase()] {
+            if !symbols.contains(&symbol) {
+                symbols.push(symbol);
+            }
+        }
+    }
+    let request_body =
+        PairsBySymbolsQuery::build_query(pairs_by_symbols_query::Variables { symbols });
+    let res: Response<pairs_by_symbols_query::ResponseData> =
+        match subgraph::query_subgraph(subgraph, &request_body) {
+            Ok(res) => res,
+            Err(error) => {
+                return Err(PairDetailQueryError::RequestError(
+                    chain.to_string(),
+                    error.to_string(),
+                ))
+            }
+        };
+    let pairs = match res.data {
+        Some(data) => data.pairs,
+        None => return Err(PairDetailQueryError::EmptyResponse(chain.to_string())),
+    };
+
+    let (symbol0, symbol1) = (symbol0.to_lowercase(), symbol1.to_lowercase());
+    Ok(pairs
+        .into_iter()
+        .filter(|pair| {
+            let (token0, token1) = (
+                pair.token0.symbol.to_lowercase(),
+                pair.token1.symbol.to_lowercase(),
+            );
+            (token0 == symbol0 && token1 == symbol1) || (token0 == symbol1 && token1 == symbol0)
+        })
+        .map(|pair| {
+            let liquidity_usd = pair.liquidity_usd.parse().unwrap_or_default();
+            (pair.id, pair.name, liquidity_usd)
+        })
+        .collect())
+}
+
+/// Number of addresses holding LP tokens of a pair, true if the count hit the page limit.
+pub fn query_lp_holders(chain: &str, id: &str) -> Result<(usize, bool), PairDetailQueryError> {
+    let subgraph = legacy_subgraph(chain)?;
+    let mut holders: usize = 0;
+    let mut last_id = String::new();
+    for _ in 0..MAX_POSITION_PAGES {
+        let request_body =
+            LiquidityPositionsQuery::build_query(liquidity_positions_query::Variables {
+                pair: id.to_lowercase(),
+                last_id: last_id.clone(),
+            });
+        let res: Response<liquidity_positions_query::ResponseData> =
+            match subgraph::query_subgraph(subgraph, &request_body) {
+                Ok(res) => res,
+                Err(error) => {
+                    return Err(PairDetailQueryError::RequestError(
+                        chain.to_string(),
+                        error.to_string(),
+                    ))
+                }
+            };
+        let positions = match res.data {
+            Some(data) => data.liquidity_positions,
+            None => return Err(PairDetailQueryError::EmptyResponse(chain.to_string())),
+        };
+        holders += positions.len();
+        match positions.last() {
+            Some(position) if positions.len() == 1000 => last_id = position.id.clone(),
+            _ => return Ok((holders, false)),
+        }
+    }
+    Ok((holders, true))
+}
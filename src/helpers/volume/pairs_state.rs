@@ -0,0 +1,126 @@
+use std::collections::HashMap;
+
+use graphql_client::{GraphQLQuery, Response};
+
+use crate::{
+    graphql::queries::pairsStateQuery::{pairs_state_query, PairsStateQuery},
+    helpers::volume::period_volume::{Pair, Protocol, Token},
+    network::LEGACY_SUBGRAPH,
+    subgraph,
+};
+
+/// Cumulative state of a pair at a block, whatever the token list.
+#[derive(Debug, Clone)]
+pub struct PairState {
+    pub id: String,
+    pub name: String,
+    pub token0: Token,
+    pub token1: Token,
+    pub protocol: Protocol,
+    pub swap_fee: u32,     //in bps
+    pub total_supply: f64, //LP tokens, in wei
+    pub liquidity_usd: f64,
+    pub volume_usd: f64,
+    pub fees_usd: f64,
+}
+
+impl PairState {
+    pub fn from(data: pairs_state_query::PairsStateQueryPairs) -> Option<PairState> {
+        Some(PairState {
+            id: data.id,
+            name: data.name,
+            token0: Token {
+                id: data.token0.id,
+                symbol: data.token0.symbol,
+            },
+            token1: Token {
+                id: data.token1.id,
+                symbol: data.token1.symbol,
+            },
+            protocol: Protocol::from_source(&data.source),
+            swap_fee: data.swap_fee.parse().ok()?,
+            total_supply: data.liquidity.parse().ok()?,
+            liquidity_usd: data.liquidity_usd.parse().ok()?,
+            volume_usd: data.volume_usd.parse().ok()?,
+            fees_usd: data.fees_usd.parse().ok()?,
+        })
+    }
+
+    /// Volume and fees between an older state of the pair (None if created since) and this one.
+    pub fn period_pair(&self, start: Option<&PairState>) -> Pair {
+        Pair {
+            id: self.id.clone(),
+            name: self.name.clone(),
+            token0: self.token0.clone(),
+            token1: self.token1.clone(),
+            protocol: self.protocol,
+            swap_fee: self.swap_fee,
+            volume_usd: self.volume_usd - start.map_or(0.0, |start| start.volume_usd),
+            fees_usd: self.fees_usd - start.map_or(0.0, |start| start.fees_usd),
+            liquidity_usd: self.liquidity_usd,
+            protocol_fee: None,
+        }
+    }
+}
+
+#[derive(Debug)]
+#[allow(dead_code)] //only read through Debug when printing errors
+pub enum PairsStateQueryError {
+    UnknownChain(String),
+    RequestError(String, String),
+    EmptyResponse(String),
+}
+
+/// State of the given pairs at a block (subgraph head if None), None for a pair returned by
+/// the subgraph that can't be parsed. Ids missing from the result aren't pairs of the exchange.
+pub fn query_pairs_state_or_unparsed(
+    chain: &str,
+    ids: &[String],
+    block: Option<u64>,
+) -> Result<HashMap<String, Option<PairState>>, PairsStateQueryError> {
+    let subgraph = match LEGACY_SUBGRAPH.get(chain) {
+        Some(subgraph) => subgraph,
+        None => return Err(PairsStateQueryError::UnknownChain(chain.to_string())),
+    };
+
+    let request_body = PairsStateQuery::build_query(pairs_state_query::Variables {
+        ids: ids.to_vec(),
+        block: block.map(|number| pairs_state_query::Block_height {
+            hash: None,
+            number: Some(number as i64),
+            number_gte: None,
+        }),
+    });
+    let res: Response<pairs_state_query::ResponseData> =
+        match subgraph::query_subgraph(subgraph, &request_body) {
+            Ok(res) => res,
+            Err(error) => {
+                return Err(PairsStateQueryError::RequestError(
+                    chain.to_string(),
+                    error.to_string(),
+                ))
+            }
+        };
+
+    match res.data {
+        Some(data) => Ok(data
+            .pairs
+            .into_iter()
+            .map(|data| (data.id.clone(), PairState::from(data)))
+            .collect()),
+        None => Err(PairsStateQueryError::EmptyResponse(chain.to_string())),
+    }
+}
+
+/// State of the given pairs at a block (subgraph head if None), pairs that can't be parsed are
+/// left out with the ones that aren't pairs of the exchange.
+pub fn query_pairs_state(
+    chain: &str,
+    ids: &[String],
+    block: Option<u64>,
+) -> Result<HashMap<String, PairState>, PairsStateQueryError> {
+    Ok(query_pairs_state_or_unparsed(chain, ids, block)?
+        .into_iter()
+        .filter_map(|(id, state)| Some((id, state?)))
+        .collect())
+}
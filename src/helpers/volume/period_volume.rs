@@ -1,38 +1,82 @@
 use crate::{
     graphql::queries::periodVolumeQuery::{period_volume_query, PeriodVolumeQuery},
     helpers::{
-        block::block_timestamp::{query_block_timestamp, BlockQueryError},
-        token_list::query_token_list,
+        block::{
+            block_rpc::query_block_by_number,
+            block_timestamp::{
+                query_block_at, query_block_timestamp, BlockQueryError, BlockSource,
+            },
+        },
+        token_list::{resolve_token_list, TokenList, TokenListConfig},
+        volume::v3_volume::query_v3_period_volume,
     },
-    network::LEGACY_SUBGRAPH,
+    network::{LEGACY_SUBGRAPH, V3_SUBGRAPH},
     subgraph,
 };
 use graphql_client::{GraphQLQuery, Response};
+use serde::{Deserialize, Serialize};
 use std::{collections::HashMap, thread};
 
-#[derive(Debug)]
+//`first` of the PeriodVolumeQuery pairs and newly created pairs
+const PAIRS_QUERY_CAP: usize = 1000;
+const NEW_PAIRS_QUERY_CAP: usize = 100;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Protocol {
+    Legacy,
+    Trident,
+    V3,
+}
+
+impl Protocol {
+    pub fn from_source(source: &str) -> Protocol {
+        match source {
+            "TRIDENT" => Protocol::Trident,
+            _ => Protocol::Legacy,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+pub struct Token {
+    pub id: String,
+    pub symbol: String,
+}
+
+#[derive(Debug, Clone)]
 pub struct Pair {
     pub id: String,
     pub name: String,
+    pub token0: Token,
+    pub token1: Token,
+    pub protocol: Protocol,
+    pub swap_fee: u32, //in bps
     pub volume_usd: f64,
     pub fees_usd: f64,
+    pub liquidity_usd: f64,        //at the end of the period
+    pub protocol_fee: Option<f64>, //share of the fees set on the pool contract, V3 only
 }
 
 impl Pair {
-    pub fn from(id: String, name: String, volume_usd: String, fees_usd: String) -> Option<Pair> {
-        let volume_usd: f64 = match volume_usd.parse() {
-            Ok(volume) => volume,
-            Err(_) => return None, //don't return pair if can't compute volume
-        };
-        let fees_usd: f64 = match fees_usd.parse() {
-            Ok(fees) => fees,
-            Err(_) => return None, //don't return pair if can't compute fees
-        };
+    pub fn from(data: period_volume_query::PairVolume) -> Option<Pair> {
         Some(Pair {
-            id,
-            name,
-            volume_usd,
-            fees_usd,
+            id: data.id,
+            name: data.name,
+            token0: Token {
+                id: data.token0.id,
+                symbol: data.token0.symbol,
+            },
+            token1: Token {
+                id: data.token1.id,
+                symbol: data.token1.symbol,
+            },
+            protocol: Protocol::from_source(&data.source),
+            swap_fee: data.swap_fee.parse().ok()?, //don't return pair if can't know its fee tier
+            volume_usd: data.volume_usd.parse().ok()?, //don't return pair if can't compute volume
+            fees_usd: data.fees_usd.parse().ok()?, //don't return pair if can't compute fees
+            liquidity_usd: data.liquidity_usd.parse().unwrap_or_default(),
+            protocol_fee: None,
         })
     }
 }
@@ -41,19 +85,14 @@ fn parse_volume(volume: period_volume_query::ResponseData) -> HashMap<String, Pa
     let mut pairs: HashMap<String, Pair> = HashMap::new();
 
     for new_pair_state in volume.new_pairs_state {
-        match Pair::from(
-            new_pair_state.id,
-            new_pair_state.name,
-            new_pair_state.volume_usd,
-            new_pair_state.fees_usd,
-        ) {
+        match Pair::from(new_pair_state) {
             Some(pair) => pairs.insert(pair.id.clone(), pair),
             None => break, //don't add pair if None
         };
     }
 
     for old_pair_state in volume.old_pairs_state {
-        let mut pair = match pairs.get_mut(&old_pair_state.id) {
+        let pair = match pairs.get_mut(&old_pair_state.id) {
             Some(pair) => pair,
             None => break, //cancel if cannot find pair
         };
@@ -78,12 +117,7 @@ fn parse_volume(volume: period_volume_query::ResponseData) -> HashMap<String, Pa
     }
 
     for newly_created_pair in volume.newly_created_pairs {
-        match Pair::from(
-            newly_created_pair.id,
-            newly_created_pair.name,
-            newly_created_pair.volume_usd,
-            newly_created_pair.fees_usd,
-        ) {
+        match Pair::from(newly_created_pair) {
             Some(pair) => pairs.insert(pair.id.clone(), pair),
             None => break, //don't add pair if None
         };
@@ -93,37 +127,106 @@ fn parse_volume(volume: period_volume_query::ResponseData) -> HashMap<String, Pa
 }
 
 #[derive(Debug)]
+#[allow(dead_code)] //only read through Debug when printing errors
 pub enum PeriodVolumeQueryError {
     UnknownChain(String),
     BlockQueryError(String, BlockQueryError),
+    TokenListError(String, String),
     RequestError(String, String),
     EmptyResponse(String),
 }
 
+/// Period covered by a volume query, counted in days back from the chain head
+/// unless the boundaries are pinned to explicit blocks.
+#[derive(Debug, Clone)]
+pub struct Period {
+    pub days: u32,
+    pub block_source: BlockSource,
+    pub token_list: TokenListConfig, //which pairs are counted
+}
+
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+pub struct BlockBoundaries {
+    pub start: Option<u64>,
+    pub end: Option<u64>,
+}
+
+#[derive(Debug)]
+pub struct PeriodVolume {
+    pub start_block: u64,
+    pub end_block: u64,
+    pub end_timestamp: Option<u64>,
+    pub token_list: TokenList,
+    pub pairs: HashMap<String, Pair>,
+    pub truncated: bool, //a query cap was hit, pairs beyond it are missing
+    pub unread_protocol_fees: Vec<String>, //V3 pools counted without their protocol fee
+}
+
+impl PeriodVolume {
+    /// Add the pairs of another protocol queried over the same period.
+    pub fn merge(&mut self, other: PeriodVolume) {
+        self.pairs.extend(other.pairs);
+        self.truncated |= other.truncated;
+        self.unread_protocol_fees.extend(other.unread_protocol_fees);
+    }
+}
+
+pub fn resolve_start_block(
+    chain: &str,
+    period: &Period,
+    boundaries: BlockBoundaries,
+) -> Result<u64, BlockQueryError> {
+    if let Some(start) = boundaries.start {
+        return Ok(start);
+    }
+    match boundaries.end {
+        Some(end) => {
+            //count the days back from the pinned end block rather than from now
+            let end = query_block_by_number(chain, end)?;
+            let timestamp = end
+                .timestamp
+                .saturating_sub(u64::from(86_400 * period.days));
+            Ok(query_block_at(chain, timestamp, period.block_source)?.number)
+        }
+        None => Ok(query_block_timestamp(chain, period.days, period.block_source)? as u64),
+    }
+}
+
 pub fn query_period_volume(
     chain: String,
-    days: u32,
-) -> Result<HashMap<String, Pair>, PeriodVolumeQueryError> {
+    period: &Period,
+    boundaries: BlockBoundaries,
+) -> Result<PeriodVolume, PeriodVolumeQueryError> {
     let subgraph = match LEGACY_SUBGRAPH.get(chain.as_str()) {
         Some(subgraph) => subgraph,
         None => return Err(PeriodVolumeQueryError::UnknownChain(chain)),
     };
 
-    let block = match query_block_timestamp(chain.as_str(), days) {
+    let block = match resolve_start_block(chain.as_str(), period, boundaries) {
         Ok(block) => block,
         Err(error) => return Err(PeriodVolumeQueryError::BlockQueryError(chain, error)),
     };
 
-    let token_list: Option<Vec<String>> = match query_token_list(chain.as_str()) {
-        Ok(token_list) => Some(token_list),
+    let token_list = match resolve_token_list(chain.as_str(), &period.token_list) {
+        Ok(token_list) => token_list,
         Err(error) => {
-            eprintln!("Error while querying token list: {:#?}", error);
-            None
+            return Err(PeriodVolumeQueryError::TokenListError(
+                chain,
+                error.to_string(),
+            ))
         }
     };
 
-    let volume_request_body =
-        PeriodVolumeQuery::build_query(period_volume_query::Variables { token_list, block });
+    let end_block = boundaries.end.map(|end| period_volume_query::Block_height {
+        hash: None,
+        number: Some(end as i64),
+        number_gte: None,
+    });
+    let volume_request_body = PeriodVolumeQuery::build_query(period_volume_query::Variables {
+        token_list: token_list.tokens.clone(),
+        block: block as i64,
+        end_block,
+    });
 
     let res: Response<period_volume_query::ResponseData> =
         match subgraph::query_subgraph(subgraph, &volume_request_body) {
@@ -137,29 +240,94 @@ pub fn query_period_volume(
         };
 
     match res.data {
-        Some(data) => Ok(parse_volume(data)),
+        Some(mut data) => {
+            let (end_block, end_timestamp) = match data.meta.take() {
+                Some(meta) => (
+                    meta.block.number as u64,
+                    meta.block.timestamp.map(|timestamp| timestamp as u64),
+                ),
+                None => (boundaries.end.unwrap_or_default(), None),
+            };
+            let truncated = data.new_pairs_state.len() >= PAIRS_QUERY_CAP
+                || data.newly_created_pairs.len() >= NEW_PAIRS_QUERY_CAP;
+            let mut pairs = parse_volume(data);
+            pairs.retain(|_, pair| !period.token_list.denies(&chain, pair));
+            Ok(PeriodVolume {
+                start_block: block,
+                end_block,
+                end_timestamp,
+                token_list,
+                pairs,
+                truncated,
+                unread_protocol_fees: vec![],
+            })
+        }
         None => Err(PeriodVolumeQueryError::EmptyResponse(chain)),
     }
 }
 
+/// Legacy/Trident and V3 volume of a chain merged, from whichever subgraphs the chain has.
+/// The start block is resolved once so both protocols cover the exact same period.
+pub fn query_chain_period_volume(
+    chain: String,
+    period: &Period,
+    boundaries: BlockBoundaries,
+) -> Result<PeriodVolume, PeriodVolumeQueryError> {
+    if !V3_SUBGRAPH.contains_key(chain.as_str()) {
+        return query_period_volume(chain, period, boundaries);
+    }
+    let boundaries = match resolve_start_block(chain.as_str(), period, boundaries) {
+        Ok(start) => BlockBoundaries {
+            start: Some(start),
+            end: boundaries.end,
+        },
+        Err(error) => return Err(PeriodVolumeQueryError::BlockQueryError(chain, error)),
+    };
+    if !LEGACY_SUBGRAPH.contains_key(chain.as_str()) {
+        return query_v3_period_volume(chain, period, boundaries);
+    }
+
+    let mut volume = match query_period_volume(chain.clone(), period, boundaries) {
+        Ok(volume) => volume,
+        Err(error) => {
+            //V3 alone is still worth reporting
+            eprintln!("Error while querying legacy volume: {:#?}", error);
+            return query_v3_period_volume(chain, period, boundaries);
+        }
+    };
+    //pin V3 to the legacy end block too
+    let v3_boundaries = BlockBoundaries {
+        start: boundaries.start,
+        end: Some(volume.end_block),
+    };
+    match query_v3_period_volume(chain, period, v3_boundaries) {
+        Ok(v3_volume) => volume.merge(v3_volume),
+        Err(error) => eprintln!("Error while querying V3 volume: {:#?}", error),
+    }
+    Ok(volume)
+}
+
+type ChainVolumeHandle = thread::JoinHandle<Result<(String, PeriodVolume), PeriodVolumeQueryError>>;
+
 pub fn query_period_volume_multichain(
     chains: Vec<String>,
-    days: u32,
-) -> HashMap<String, HashMap<String, Pair>> {
-    let mut handles: Vec<
-        thread::JoinHandle<
-            Result<(String, HashMap<std::string::String, Pair>), PeriodVolumeQueryError>,
-        >,
-    > = vec![];
+    period: Period,
+    boundaries: &HashMap<String, BlockBoundaries>,
+) -> HashMap<String, PeriodVolume> {
+    let mut handles: Vec<ChainVolumeHandle> = vec![];
     for chain in chains {
-        let handle = thread::spawn(move || match query_period_volume(chain.clone(), days) {
-            Ok(volume) => Ok((chain, volume)),
-            Err(error) => Err(error),
+        let chain_boundaries = boundaries.get(&chain).copied().unwrap_or_default();
+        let period = period.clone();
+        let handle = thread::spawn(move || {
+            match query_chain_period_volume(chain.clone(), &period, chain_boundaries) {
+                Ok(volume) => Ok((chain, volume)),
+                Err(error) => Err(error),
+            }
         });
         handles.push(handle);
     }
 
-    let mut chain_data: HashMap<String, HashMap<String, Pair>> = HashMap::new();
+    let mut chain_data: HashMap<String, PeriodVolume> = HashMap::new();
     for handle in handles {
         match handle.join().unwrap() {
             Ok(volume) => chain_data.insert(volume.0, volume.1),
@@ -0,0 +1,316 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    error::Error,
+    thread::{self, JoinHandle},
+};
+
+use graphql_client::{GraphQLQuery, Response};
+
+use crate::{
+    graphql::queries::{
+        factorySnapshotsQuery::{factory_snapshots_query, FactorySnapshotsQuery},
+        pairSnapshotsQuery::{pair_snapshots_query, PairSnapshotsQuery},
+        pairsDaySnapshotsQuery::{pairs_day_snapshots_query, PairsDaySnapshotsQuery},
+    },
+    network::LEGACY_SUBGRAPH,
+    subgraph,
+};
+
+const MAX_SNAPSHOT_PAGES: i64 = 5; //the subgraph refuses to skip more than 5000 entities
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Granularity {
+    Day,
+    Hour,
+}
+
+/// Volume, fees and liquidity of a day or an hour starting at `date`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Snapshot {
+    pub date: u64,
+    pub volume_usd: f64,
+    pub fees_usd: f64,
+    pub liquidity_usd: f64,
+    pub tx_count: u64,
+}
+
+impl Snapshot {
+    fn parse(
+        date: i64,
+        volume_usd: &str,
+        fees_usd: &str,
+        liquidity_usd: &str,
+        tx_count: &str,
+    ) -> Option<Snapshot> {
+        Some(Snapshot {
+            date: date as u64,
+            volume_usd: volume_usd.parse().ok()?,
+            fees_usd: fees_usd.parse().ok()?,
+            liquidity_usd: liquidity_usd.parse().ok()?,
+            tx_count: tx_count.parse().ok()?,
+        })
+    }
+
+    fn add(&mut self, other: &Snapshot) {
+        self.volume_usd += other.volume_usd;
+        self.fees_usd += other.fees_usd;
+        self.liquidity_usd += other.liquidity_usd;
+        self.tx_count += other.tx_count;
+    }
+}
+
+/// Sum snapshots sharing a date, sorted by date.
+pub fn sum_by_date(snapshots: &[Snapshot]) -> Vec<Snapshot> {
+    let mut dates: BTreeMap<u64, Snapshot> = BTreeMap::new();
+    for snapshot in snapshots {
+        dates
+            .entry(snapshot.date)
+            .or_insert(Snapshot {
+                date: snapshot.date,
+                ..Default::default()
+            })
+            .add(snapshot);
+    }
+    dates.into_values().collect()
+}
+
+/// Mean liquidity of the snapshots, None without any.
+pub fn average_liquidity(snapshots: &[Snapshot]) -> Option<f64> {
+    match snapshots.len() {
+        0 => None,
+        len => Some(
+            snapshots
+                .iter()
+                .map(|snapshot| snapshot.liquidity_usd)
+                .sum::<f64>()
+                / len as f64,
+        ),
+    }
+}
+
+#[derive(Debug)]
+#[allow(dead_code)] //only read through Debug when printing errors
+pub enum SnapshotsQueryError {
+    UnknownChain(String),
+    RequestError(String, String),
+    EmptyResponse(String),
+}
+
+type SnapshotsPage = Result<Option<Vec<Option<Snapshot>>>, Box<dyn Error>>;
+
+//query_page returns a page of parsed snapshots (None if unparsable) from a subgraph and a skip
+fn query_pages<F>(chain: &str, query_page: F) -> Result<Vec<Snapshot>, SnapshotsQueryError>
+where
+    F: Fn(&str, i64) -> SnapshotsPage,
+{
+    let subgraph = match LEGACY_SUBGRAPH.get(chain) {
+        Some(subgraph) => subgraph,
+        None => return Err(SnapshotsQueryError::UnknownChain(chain.to_string())),
+    };
+    let mut snapshots: Vec<Snapshot> = vec![];
+    for page in 0..MAX_SNAPSHOT_PAGES {
+        let entities = match query_page(subgraph, page * 1000) {
+            Ok(Some(entities)) => entities,
+            Ok(None) => return Err(SnapshotsQueryError::EmptyResponse(chain.to_string())),
+            Err(error) => {
+                return Err(SnapshotsQueryError::RequestError(
+                    chain.to_string(),
+                    error.to_string(),
+                ))
+            }
+        };
+        let count = entities.len();
+        snapshots.extend(entities.into_iter().flatten()); //skip snapshots that can't be parsed
+        if count < 1000 {
+            break;
+        }
+    }
+    Ok(snapshots)
+}
+
+macro_rules! parse_snapshots {
+    ($entities:expr) => {
+        $entities.map(|entities| {
+            entities
+                .into_iter()
+                .map(|data| {
+                    Snapshot::parse(
+                        data.date,
+                        &data.volume_usd,
+                        &data.fees_usd,
+                        &data.liquidity_usd,
+                        &data.transaction_count,
+                    )
+                })
+                .collect()
+        })
+    };
+}
+
+/// Snapshots of every factory of a chain since `from`, whatever the token list.
+/// Factories (legacy and trident pools) are summed by date.
+pub fn query_factory_snapshots(
+    chain: &str,
+    from: u64,
+    granularity: Granularity,
+) -> Result<Vec<Snapshot>, SnapshotsQueryError> {
+    let hourly = granularity == Granularity::Hour;
+    let snapshots = query_pages(chain, |subgraph, skip| {
+        let request_body = FactorySnapshotsQuery::build_query(factory_snapshots_query::Variables {
+            from: from as i64,
+            skip,
+            hourly,
+        });
+        let res: Response<factory_snapshots_query::ResponseData> =
+            subgraph::query_subgraph(subgraph, &request_body)?;
+        //day and hour snapshots are distinct types, only the queried one is set
+        Ok(match (res.data, hourly) {
+            (Some(data), false) => parse_snapshots!(data.factory_day_snapshots),
+            (Some(data), true) => parse_snapshots!(data.factory_hour_snapshots),
+            (None, _) => None,
+        })
+    })?;
+    Ok(sum_by_date(&snapshots))
+}
+
+/// Snapshots of a single pair since `from`.
+pub fn query_pair_snapshots(
+    chain: &str,
+    pair: &str,
+    from: u64,
+    granularity: Granularity,
+) -> Result<Vec<Snapshot>, SnapshotsQueryError> {
+    let hourly = granularity == Granularity::Hour;
+    let variables = |skip| pair_snapshots_query::Variables {
+        pair: pair.to_lowercase(),
+        from: from as i64,
+        skip,
+        hourly,
+    };
+    let snapshots = query_pages(chain, |subgraph, skip| {
+        let request_body = PairSnapshotsQuery::build_query(variables(skip));
+        let res: Response<pair_snapshots_query::ResponseData> =
+            subgraph::query_subgraph(subgraph, &request_body)?;
+        Ok(match (res.data, hourly) {
+            (Some(data), false) => parse_snapshots!(data.pair_day_snapshots),
+            (Some(data), true) => parse_snapshots!(data.pair_hour_snapshots),
+            (None, _) => None,
+        })
+    })?;
+    Ok(sum_by_date(&snapshots))
+}
+
+/// Mean daily liquidity of the given pairs between two timestamps, pairs without snapshot are
+/// left out.
+pub fn query_pairs_average_liquidity(
+    chain: &str,
+    ids: Vec<String>,
+    (from, to): (u64, u64),
+) -> Result<HashMap<String, f64>, SnapshotsQueryError> {
+    let subgraph = match LEGACY_SUBGRAPH.get(chain) {
+        Some(subgraph) => subgraph,
+        None => return Err(SnapshotsQueryError::UnknownChain(chain.to_string())),
+    };
+    let mut liquidities: HashMap<String, Vec<f64>> = HashMap::new();
+    for page in 0..MAX_SNAPSHOT_PAGES {
+        let request_body =
+            PairsDaySnapshotsQuery::build_query(pairs_day_snapshots_query::Variables {
+                pairs: ids.clone(),
+                from: from as i64,
+                skip: page * 1000,
+            });
+        let res: Response<pairs_day_snapshots_query::ResponseData> =
+            match subgraph::query_subgraph(subgraph, &request_body) {
+                Ok(res) => res,
+                Err(error) => {
+                    return Err(SnapshotsQueryError::RequestError(
+                        chain.to_string(),
+                        error.to_string(),
+                    ))
+                }
+            };
+        let snapshots = match res.data {
+            Some(data) => data.pair_day_snapshots,
+            None => return Err(SnapshotsQueryError::EmptyResponse(chain.to_string())),
+        };
+        let count = snapshots.len();
+        for snapshot in snapshots
+            .into_iter()
+            .filter(|snapshot| snapshot.date as u64 <= to)
+        {
+            if let Ok(liquidity) = snapshot.liquidity_usd.parse::<f64>() {
+                liquidities
+                    .entry(snapshot.pair.id)
+                    .or_default()
+                    .push(liquidity);
+            }
+        }
+        if count < 1000 {
+            break;
+        }
+    }
+    Ok(liquidities
+        .into_iter()
+        .map(|(id, liquidity)| (id, liquidity.iter().sum::<f64>() / liquidity.len() as f64))
+        .collect())
+}
+
+type ChainSnapshotsHandle = JoinHandle<Result<(String, Vec<Snapshot>), SnapshotsQueryError>>;
+
+pub fn query_factory_snapshots_multichain(
+    chains: Vec<String>,
+    from: u64,
+    granularity: Granularity,
+) -> HashMap<String, Vec<Snapshot>> {
+    let mut handles: Vec<ChainSnapshotsHandle> = vec![];
+    for chain in chains {
+        handles.push(thread::spawn(move || {
+            match query_factory_snapshots(&chain, from, granularity) {
+                Ok(snapshots) => Ok((chain, snapshots)),
+                Err(error) => Err(error),
+            }
+        }));
+    }
+
+    let mut result: HashMap<String, Vec<Snapshot>> = HashMap::new();
+    for handle in handles {
+        match handle.join().unwrap() {
+            Ok((chain, snapshots)) => result.insert(chain, snapshots),
+            Err(error) => {
+                eprintln!("Error while querying snapshots: {:#?}", error);
+                continue;
+            }
+        };
+    }
+    result
+}
+
+type ChainPairsLiquidityHandle =
+    JoinHandle<Result<(String, HashMap<String, f64>), SnapshotsQueryError>>;
+
+/// query_pairs_average_liquidity on many chains at once, with the pairs and period of each chain.
+pub fn query_pairs_average_liquidity_multichain(
+    requests: Vec<(String, Vec<String>, (u64, u64))>,
+) -> HashMap<String, HashMap<String, f64>> {
+    let mut handles: Vec<ChainPairsLiquidityHandle> = vec![];
+    for (chain, ids, period) in requests {
+        handles.push(thread::spawn(move || {
+            match query_pairs_average_liquidity(&chain, ids, period) {
+                Ok(liquidities) => Ok((chain, liquidities)),
+                Err(error) => Err(error),
+            }
+        }));
+    }
+
+    let mut result: HashMap<String, HashMap<String, f64>> = HashMap::new();
+    for handle in handles {
+        match handle.join().unwrap() {
+            Ok((chain, liquidities)) => result.insert(chain, liquidities),
+            Err(error) => {
+                eprintln!("Error while querying pairs liquidity: {:#?}", error);
+                continue;
+            }
+        };
+    }
+    result
+}
@@ -0,0 +1,98 @@
+use graphql_client::{GraphQLQuery, Response};
+
+use crate::{
+    graphql::queries::swapsQuery::{swaps_query, SwapsQuery},
+    network::LEGACY_SUBGRAPH,
+    subgraph,
+};
+
+#[derive(Debug, Clone)]
+pub struct Swap {
+    pub timestamp: u64,
+    pub pair_name: String,
+    pub sender: String, //router or aggregator for most swaps
+    pub to: String,
+    pub amount_usd: f64,
+    pub transaction: String,
+}
+
+//cursor of the last swap fetched, pages are ordered by timestamp then id
+struct SwapsCursor {
+    timestamp: String,
+    id: String,
+}
+
+impl Swap {
+    fn from(data: swaps_query::SwapsQuerySwaps) -> Option<Swap> {
+        Some(Swap {
+            timestamp: data.timestamp.parse().ok()?,
+            pair_name: data.pair.name,
+            sender: data.sender.to_lowercase(),
+            to: data.to.to_lowercase(),
+            amount_usd: data.amount_usd.parse().ok()?,
+            transaction: data.transaction.id,
+        })
+    }
+}
+
+#[derive(Debug)]
+#[allow(dead_code)] //only read through Debug when printing errors
+pub enum SwapsQueryError {
+    UnknownChain(String),
+    RequestError(String, String),
+    EmptyResponse(String),
+}
+
+/// Swaps of a chain since a timestamp, of some pairs only if set, the first max_swaps in time.
+/// Returns true along the swaps if they were truncated.
+pub fn query_swaps(
+    chain: &str,
+    from: u64,
+    pairs: Option<Vec<String>>,
+    max_swaps: usize,
+) -> Result<(Vec<Swap>, bool), SwapsQueryError> {
+    let subgraph = match LEGACY_SUBGRAPH.get(chain) {
+        Some(subgraph) => subgraph,
+        None => return Err(SwapsQueryError::UnknownChain(chain.to_string())),
+    };
+
+    let mut swaps: Vec<Swap> = vec![];
+    let mut cursor = SwapsCursor {
+        timestamp: from.saturating_sub(1).to_string(), //swaps after it, so from included
+        id: String::new(),
+    };
+    while swaps.len() < max_swaps {
+        let request_body = SwapsQuery::build_query(swaps_query::Variables {
+            last_timestamp: cursor.timestamp.clone(),
+            last_id: cursor.id.clone(),
+            pairs: pairs.clone(),
+        });
+        let res: Response<swaps_query::ResponseData> =
+            match subgraph::query_subgraph(subgraph, &request_body) {
+                Ok(res) => res,
+                Err(error) => {
+                    return Err(SwapsQueryError::RequestError(
+                        chain.to_string(),
+                        error.to_string(),
+                    ))
+                }
+            };
+        let page = match res.data {
+            Some(data) => data.swaps,
+            None => return Err(SwapsQueryError::EmptyResponse(chain.to_string())),
+        };
+        let count = page.len();
+        if let Some(last) = page.last() {
+            cursor = SwapsCursor {
+                timestamp: last.timestamp.clone(),
+                id: last.id.clone(),
+            };
+        }
+        swaps.extend(page.into_iter().filter_map(Swap::from)); //skip swaps that can't be parsed
+        if count < 1000 {
+            return Ok((swaps, false));
+        }
+    }
+    swaps.truncate(max_swaps);
+    Ok((swaps, true))
+}
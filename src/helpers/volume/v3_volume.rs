@@ -0,0 +1,257 @@
+use std::collections::HashMap;
+
+use ethers::{abi, types::Bytes, utils::id};
+use graphql_client::{GraphQLQuery, Response};
+
+use crate::{
+    graphql::queries::{
+        v3PoolsQuery::{v3_pools_query, V3PoolsQuery},
+        v3PoolsStateQuery::{v3_pools_state_query, V3PoolsStateQuery},
+    },
+    helpers::{
+        token_list::resolve_token_list,
+        volume::period_volume::{
+            resolve_start_block, BlockBoundaries, Pair, Period, PeriodVolume,
+            PeriodVolumeQueryError, Protocol, Token,
+        },
+    },
+    network::{NETWORKS, V3_SUBGRAPH},
+    rpc, subgraph,
+};
+
+const POOLS_QUERY_CAP: usize = 1000; //`first` of the V3PoolsQuery
+
+/// Share of the swap fees taken by the protocol from a pool `feeProtocol`, which packs
+/// the token0 and token1 denominators in 4 bits each (0 = off, N = 1/N of the fees).
+pub fn protocol_fee_share(fee_protocol: u32) -> f64 {
+    let share = |denominator: u32| match denominator {
+        0 => 0.0,
+        denominator => 1.0 / denominator as f64,
+    };
+    (share(fee_protocol % 16) + share(fee_protocol >> 4)) / 2.0
+}
+
+fn decode_fee_protocol(result: &Bytes) -> Result<u32, String> {
+    let slot0 = [
+        abi::ParamType::Uint(160), //sqrtPriceX96
+        abi::ParamType::Int(24),   //tick
+        abi::ParamType::Uint(16),  //observationIndex
+        abi::ParamType::Uint(16),  //observationCardinality
+        abi::ParamType::Uint(16),  //observationCardinalityNext
+        abi::ParamType::Uint(8),   //feeProtocol
+        abi::ParamType::Bool,      //unlocked
+    ];
+    match abi::decode(&slot0, result).map(|tokens| tokens.into_iter().nth(5)) {
+        Ok(Some(abi::Token::Uint(fee_protocol))) => Ok(fee_protocol.as_u32()),
+        _ => Err(format!("unexpected slot0 result {result}")),
+    }
+}
+
+/// The subgraph doesn't index the protocol fee, read `feeProtocol` from the pools `slot0()`
+/// in batched calls. Pools whose read failed are returned apart with their error.
+fn query_fee_protocols(
+    chain: &str,
+    pools: &[String],
+    block: u64,
+) -> (HashMap<String, u32>, Vec<(String, String)>) {
+    let rpc_url = match NETWORKS.get(chain) {
+        Some(network) => network.rpc,
+        None => {
+            let error = format!("no rpc for {chain}");
+            return (
+                HashMap::new(),
+                pools
+                    .iter()
+                    .map(|pool| (pool.clone(), error.clone()))
+                    .collect(),
+            );
+        }
+    };
+
+    let calls: Vec<(String, Vec<u8>)> = pools
+        .iter()
+        .map(|pool| (pool.clone(), id("slot0()").to_vec()))
+        .collect();
+    let mut fee_protocols = HashMap::new();
+    let mut failed = vec![];
+    for (pool, result) in pools
+        .iter()
+        .zip(rpc::eth_call_batch(rpc_url, &calls, Some(block)))
+    {
+        match result.and_then(|result| decode_fee_protocol(&result)) {
+            Ok(fee_protocol) => {
+                fee_protocols.insert(pool.clone(), fee_protocol);
+            }
+            Err(error) => failed.push((pool.clone(), error)),
+        }
+    }
+    (fee_protocols, failed)
+}
+
+impl Pair {
+    fn from_v3(data: v3_pools_query::V3PoolsQueryPools) -> Option<Pair> {
+        let fee_tier: u32 = data.fee_tier.parse().ok()?; //in hundredths of bps
+        Some(Pair {
+            id: data.id,
+            name: format!("{}-{}", data.token0.symbol, data.token1.symbol),
+            token0: Token {
+                id: data.token0.id,
+                symbol: data.token0.symbol,
+            },
+            token1: Token {
+                id: data.token1.id,
+                symbol: data.token1.symbol,
+            },
+            protocol: Protocol::V3,
+            swap_fee: fee_tier / 100,
+            volume_usd: data.volume_usd.parse().ok()?,
+            fees_usd: data.fees_usd.parse().ok()?,
+            liquidity_usd: data.total_value_locked_usd.parse().ok()?,
+            protocol_fee: None, //read onchain once the period volume is known
+        })
+    }
+}
+
+fn block_height(number: Option<u64>) -> Option<v3_pools_query::Block_height> {
+    number.map(|number| v3_pools_query::Block_height {
+        hash: None,
+        number: Some(number as i64),
+        number_gte: None,
+    })
+}
+
+pub fn query_v3_period_volume(
+    chain: String,
+    period: &Period,
+    boundaries: BlockBoundaries,
+) -> Result<PeriodVolume, PeriodVolumeQueryError> {
+    let subgraph = match V3_SUBGRAPH.get(chain.as_str()) {
+        Some(subgraph) => subgraph,
+        None => return Err(PeriodVolumeQueryError::UnknownChain(chain)),
+    };
+
+    let block = match resolve_start_block(chain.as_str(), period, boundaries) {
+        Ok(block) => block,
+        Err(error) => return Err(PeriodVolumeQueryError::BlockQueryError(chain, error)),
+    };
+
+    let token_list = match resolve_token_list(chain.as_str(), &period.token_list) {
+        Ok(token_list) => token_list,
+        Err(error) => {
+            return Err(PeriodVolumeQueryError::TokenListError(
+                chain,
+                error.to_string(),
+            ))
+        }
+    };
+
+    //pools at the end of the period first, then their state at the start
+    let pools_request_body = V3PoolsQuery::build_query(v3_pools_query::Variables {
+        token_list: token_list.tokens.clone(),
+        block: block_height(boundaries.end),
+    });
+    let res: Response<v3_pools_query::ResponseData> =
+        match subgraph::query_subgraph(subgraph, &pools_request_body) {
+            Ok(res) => res,
+            Err(error) => {
+                return Err(PeriodVolumeQueryError::RequestError(
+                    chain,
+                    error.to_string(),
+                ))
+            }
+        };
+    let data = match res.data {
+        Some(data) => data,
+        None => return Err(PeriodVolumeQueryError::EmptyResponse(chain)),
+    };
+    let (end_block, end_timestamp) = match data.meta {
+        Some(meta) => (
+            meta.block.number as u64,
+            meta.block.timestamp.map(|timestamp| timestamp as u64),
+        ),
+        None => (boundaries.end.unwrap_or_default(), None),
+    };
+    let truncated = data.pools.len() >= POOLS_QUERY_CAP;
+    let mut pairs: HashMap<String, Pair> = data
+        .pools
+        .into_iter()
+        .filter_map(Pair::from_v3) //skip pools that can't be parsed
+        .filter(|pair| !period.token_list.denies(&chain, pair))
+        .map(|pair| (pair.id.clone(), pair))
+        .collect();
+
+    let state_request_body = V3PoolsStateQuery::build_query(v3_pools_state_query::Variables {
+        ids: pairs.keys().cloned().collect(),
+        block: Some(v3_pools_state_query::Block_height {
+            hash: None,
+            number: Some(block as i64),
+            number_gte: None,
+        }),
+    });
+    let res: Response<v3_pools_state_query::ResponseData> =
+        match subgraph::query_subgraph(subgraph, &state_request_body) {
+            Ok(res) => res,
+            Err(error) => {
+                return Err(PeriodVolumeQueryError::RequestError(
+                    chain,
+                    error.to_string(),
+                ))
+            }
+        };
+    let old_pools = match res.data {
+        Some(data) => data.pools,
+        None => return Err(PeriodVolumeQueryError::EmptyResponse(chain)),
+    };
+
+    //pools missing at the start were created during the period
+    for old_pool in old_pools {
+        let (old_volume_usd, old_fees_usd) = match (
+            old_pool.volume_usd.parse::<f64>(),
+            old_pool.fees_usd.parse::<f64>(),
+        ) {
+            (Ok(volume), Ok(fees)) => (volume, fees),
+            _ => {
+                //remove pool if can't compute old volume or fees
+                pairs.remove(&old_pool.id);
+                continue;
+            }
+        };
+        if let Some(pair) = pairs.get_mut(&old_pool.id) {
+            pair.volume_usd -= old_volume_usd;
+            pair.fees_usd -= old_fees_usd;
+        }
+    }
+
+    //only pools with fees over the period need their protocol fee
+    let fee_pools: Vec<String> = pairs
+        .values()
+        .filter(|pair| pair.fees_usd > 0.0)
+        .map(|pair| pair.id.clone())
+        .collect();
+    let (fee_protocols, failed) = query_fee_protocols(&chain, &fee_pools, end_block);
+    for (pool, fee_protocol) in fee_protocols {
+        if let Some(pair) = pairs.get_mut(&pool) {
+            pair.protocol_fee = Some(protocol_fee_share(fee_protocol));
+        }
+    }
+    let mut unread_protocol_fees = vec![];
+    for (pool, error) in failed {
+        eprintln!(
+            "Error while reading {} protocol fee on {}: {}",
+            pool, chain, error
+        );
+        if let Some(pair) = pairs.get(&pool) {
+            unread_protocol_fees.push(pair.name.clone());
+        }
+    }
+
+    Ok(PeriodVolume {
+        start_block: block,
+        end_block,
+        end_timestamp,
+        token_list,
+        pairs,
+        truncated,
+        unread_protocol_fees,
+    })
+}
@@ -1,6 +1,7 @@
 mod graphql;
 mod helpers;
 mod network;
+mod rpc;
 mod scli;
 mod subgraph;
 
@@ -25,6 +25,7 @@ pub enum NetworkName {
 
 #[derive(Debug)]
 pub struct Network<'a> {
+    #[allow(dead_code)]
     pub name: NetworkName,
     pub chain_id: u32,
     pub rpc: &'a str,
@@ -146,6 +147,7 @@ pub static LEGACY_SUBGRAPH: phf::Map<&'static str, &'static str> = phf_map! {
     "boba_bnb" => "https://subgraphs.sushi.com/subgraphs/name/sushi-0m/sushiswap-boba-bnb"
 };
 
+#[allow(dead_code)]
 pub static TRIDENT_SUBGRAPH: phf::Map<&'static str, &'static str> = phf_map! {
     "arbitrum" => "https://api.thegraph.com/subgraphs/name/sushi-v2/trident-arbitrum",
     "polygon" => "https://api.thegraph.com/subgraphs/name/sushi-v2/trident-polygon",
@@ -157,25 +159,82 @@ pub static TRIDENT_SUBGRAPH: phf::Map<&'static str, &'static str> = phf_map! {
     "bttc" => "https://subgraphs.sushi.com/subgraphs/name/sushi-v2/trident-bttc"
 };
 
+//Sushi V3 (concentrated liquidity) deployments, uniswap-v3 style schema
+pub static V3_SUBGRAPH: phf::Map<&'static str, &'static str> = phf_map! {
+    "ethereum" => "https://api.thegraph.com/subgraphs/name/sushi-v3/v3-ethereum",
+    "arbitrum" => "https://api.thegraph.com/subgraphs/name/sushi-v3/v3-arbitrum",
+    "polygon" => "https://api.thegraph.com/subgraphs/name/sushi-v3/v3-polygon",
+    "optimism" => "https://api.thegraph.com/subgraphs/name/sushi-v3/v3-optimism",
+    "bsc" => "https://api.thegraph.com/subgraphs/name/sushi-v3/v3-bsc",
+    "avalanche" => "https://api.thegraph.com/subgraphs/name/sushi-v3/v3-avalanche",
+    "fantom" => "https://api.thegraph.com/subgraphs/name/sushi-v3/v3-fantom",
+    "gnosis" => "https://api.thegraph.com/subgraphs/name/sushi-v3/v3-gnosis",
+    "moonriver" => "https://api.thegraph.com/subgraphs/name/sushi-v3/v3-moonriver",
+    "boba" => "https://api.thegraph.com/subgraphs/name/sushi-v3/v3-boba",
+    "arbitrum_nova" => "https://subgraphs.sushi.com/subgraphs/name/sushi-v3/v3-arbitrum-nova"
+};
+
+//Uniswap v2 forks benchmarked by market-share, see helpers::volume::dex_adapter
+pub static UNISWAP_V2_SUBGRAPH: phf::Map<&'static str, &'static str> = phf_map! {
+    "ethereum" => "https://api.thegraph.com/subgraphs/name/uniswap/uniswap-v2"
+};
+
+pub static PANCAKESWAP_SUBGRAPH: phf::Map<&'static str, &'static str> = phf_map! {
+    "bsc" => "https://bsc.streamingfast.io/subgraphs/name/pancakeswap/exchange-v2"
+};
+
+pub static QUICKSWAP_SUBGRAPH: phf::Map<&'static str, &'static str> = phf_map! {
+    "polygon" => "https://api.thegraph.com/subgraphs/name/sameepsi/quickswap06"
+};
+
+pub static SPOOKYSWAP_SUBGRAPH: phf::Map<&'static str, &'static str> = phf_map! {
+    "fantom" => "https://api.thegraph.com/subgraphs/name/eerieeight/spookyswap"
+};
+
+pub const MASTERCHEF_ADDRESS: &str = "0xc2edad668740f1aa35e4d8f227fb8e17dca888cd";
+pub const MASTERCHEFV2_ADDRESS: &str = "0xef0881ec094552b2e128cf945ef17a6752b4ec5d";
+
+pub static MINICHEF_ADDRESS: phf::Map<&'static str, &'static str> = phf_map! {
+    "polygon" => "0x0769fd68dfb93167989c6f7254cd0d766fb2841f",
+    "gnosis" => "0xddcbf776df3de60163066a5dddf2277cb445e0f3",
+    "arbitrum" => "0xf4d73326c13a4fc5fd7a064217e12780e9bd62c3",
+    "celo" => "0x8084936982d089130e001b470edf58faca445008",
+    "moonriver" => "0x3db01570d97631f69bbb0ba39796865456cf89a5",
+    "fuse" => "0x182cd0c6f1faec0aed2ea83cd0e160c8bd4cb063",
+    "fantom" => "0xf731202a3cf7efa9368c2d7bd613926f7a144db5",
+    "moonbeam" => "0x011e52e4e40cf9498c79273329e8827b21e2e581",
+    "kava" => "0xf731202a3cf7efa9368c2d7bd613926f7a144db5",
+    "metis" => "0x1334c8e873e1cae8467156e2a81d1c8b566b2da1",
+    "boba" => "0x75f52766a6a23f736edefcd69dfbe6153a48c3f3",
+    "arbitrum_nova" => "0xc09756432dad2ff50b2d40618f7b04546dd20043",
+    "bttc" => "0xc09756432dad2ff50b2d40618f7b04546dd20043"
+};
+
 pub const MASTERCHEF_SUBGRAPH: &str =
     "https://api.thegraph.com/subgraphs/name/jiro-ono/masterchef-staging";
 
 pub const MASTERCHEFV2_SUBGRAPH: &str =
     "https://api.thegraph.com/subgraphs/name/sushiswap/master-chefv2";
 pub const MASTERCHEFV2_TOKEN: &str = "0xa5e3142b7a5d59f778483a7e0fd3fe4e263388e9";
-pub const MASTERCHEF_DUMMY_TOKENS: [&str; 11] = [
-    "0xfb736dad22b879f055c7aebf3a2e8a197f923ea1", //reduce
-    "0x393b6dc9b00e18314888678721ec0e923fc5f49d", //kava
-    "0x11b66abb675b955bd6f066fde849442865c60e29", //op
-    "0x47a307e3167820daf22a377d777371753758f59c", //polygon
-    "0xcb277e48526f30f625e24850cf293d89301ea470", //bttc
-    "0x65550c7f7280579f34999358b5234f45cfadf50f", //boba
-    "0x69cb9f3d42cf6e3706d62db661c30d048220637a", //arbi nova
-    "0x8f7a3ca0c676cee87c34d5d9c9cab5a51e929984", //kava
-    "0xe7e656893030187f1073e5b2d768e3c1e8861f26", //bsc
-    "0xdf4395818bb8b1a45d4ca6ac9c685646c517f274", //ftm
-    "0x2377e68f0f909e208884e707db4a7cd493911280", //metis
-];
+//dummy tokens staked in MasterChef v1 to mint the SUSHI bridged to other chains, by destination.
+//Both kava tokens come from the original list, which labels them kava without telling them apart
+//(likely a replaced pool), their funding is summed and a wrong label shows up as over funding.
+pub static MASTERCHEF_DUMMY_TOKENS: phf::Map<&'static str, &'static str> = phf_map! {
+    "0xfb736dad22b879f055c7aebf3a2e8a197f923ea1" => REDUCE_DUMMY_CHAIN,
+    "0x393b6dc9b00e18314888678721ec0e923fc5f49d" => "kava",
+    "0x11b66abb675b955bd6f066fde849442865c60e29" => "optimism",
+    "0x47a307e3167820daf22a377d777371753758f59c" => "polygon",
+    "0xcb277e48526f30f625e24850cf293d89301ea470" => "bttc",
+    "0x65550c7f7280579f34999358b5234f45cfadf50f" => "boba",
+    "0x69cb9f3d42cf6e3706d62db661c30d048220637a" => "arbitrum_nova",
+    "0x8f7a3ca0c676cee87c34d5d9c9cab5a51e929984" => "kava",
+    "0xe7e656893030187f1073e5b2d768e3c1e8861f26" => "bsc",
+    "0xdf4395818bb8b1a45d4ca6ac9c685646c517f274" => "fantom",
+    "0x2377e68f0f909e208884e707db4a7cd493911280" => "metis",
+};
+
+//dummy pool only there to reduce mainnet emissions, its SUSHI isn't bridged anywhere
+pub const REDUCE_DUMMY_CHAIN: &str = "reduce";
 
 pub static MINICHEF_SUBGRAPH: phf::Map<&'static str, &'static str> = phf_map! {
     "polygon" => "https://api.thegraph.com/subgraphs/name/jiro-ono/minichef-staging-updates",
@@ -0,0 +1,137 @@
+use ethers::types::Bytes;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::{collections::HashMap, error::Error};
+
+const BATCH_SIZE: usize = 100; //public rpcs refuse larger batches
+
+#[derive(Serialize)]
+struct RpcRequest<'a, P: Serialize> {
+    jsonrpc: &'a str,
+    id: u32,
+    method: &'a str,
+    params: P,
+}
+
+#[derive(Serialize)]
+struct CallRequest {
+    to: String,
+    data: String,
+}
+
+#[derive(Deserialize, Debug)]
+struct RpcResponse<R> {
+    #[serde(default)]
+    id: u32,
+    result: Option<R>,
+    error: Option<RpcError>,
+}
+
+#[derive(Deserialize, Debug)]
+struct RpcError {
+    code: i64,
+    message: String,
+}
+
+pub fn query_rpc<P, R>(url: &str, method: &str, params: P) -> Result<R, Box<dyn Error>>
+where
+    P: Serialize,
+    R: std::fmt::Debug + DeserializeOwned,
+{
+    let client = reqwest::blocking::Client::new();
+    let request_body = RpcRequest {
+        jsonrpc: "2.0",
+        id: 1,
+        method,
+        params,
+    };
+    let res: reqwest::blocking::Response = client.post(url).json(&request_body).send()?;
+    let response_body: RpcResponse<R> = res.json()?;
+    match (response_body.result, response_body.error) {
+        (Some(result), _) => Ok(result),
+        (None, Some(error)) => Err(format!("rpc error {}: {}", error.code, error.message).into()),
+        (None, None) => Err(format!("empty rpc response for {method}").into()),
+    }
+}
+
+fn block_tag(block: Option<u64>) -> String {
+    match block {
+        Some(number) => format!("{number:#x}"),
+        None => "latest".to_string(),
+    }
+}
+
+/// `eth_call` at `block`, or at the latest block when none is given.
+pub fn eth_call(
+    url: &str,
+    to: &str,
+    data: Vec<u8>,
+    block: Option<u64>,
+) -> Result<Bytes, Box<dyn Error>> {
+    let request = CallRequest {
+        to: to.to_string(),
+        data: Bytes::from(data).to_string(),
+    };
+    query_rpc(url, "eth_call", (request, block_tag(block)))
+}
+
+/// `eth_call`s sent as JSON-RPC batches, with a result per call in the same order. A failed
+/// batch request fails every call, a reverted call only its own result.
+pub fn eth_call_batch(
+    url: &str,
+    calls: &[(String, Vec<u8>)],
+    block: Option<u64>,
+) -> Vec<Result<Bytes, String>> {
+    let client = reqwest::blocking::Client::new();
+    let tag = block_tag(block);
+    let mut results = vec![];
+    for (chunk_index, chunk) in calls.chunks(BATCH_SIZE).enumerate() {
+        let first_id = chunk_index * BATCH_SIZE;
+        let request_body: Vec<RpcRequest<(CallRequest, &str)>> = chunk
+            .iter()
+            .enumerate()
+            .map(|(index, (to, data))| RpcRequest {
+                jsonrpc: "2.0",
+                id: (first_id + index) as u32,
+                method: "eth_call",
+                params: (
+                    CallRequest {
+                        to: to.clone(),
+                        data: Bytes::from(data.clone()).to_string(),
+                    },
+                    tag.as_str(),
+                ),
+            })
+            .collect();
+        let responses: Result<Vec<RpcResponse<Bytes>>, Box<dyn Error>> = client
+            .post(url)
+            .json(&request_body)
+            .send()
+            .and_then(|res| res.json())
+            .map_err(|error| error.into());
+        let mut responses: HashMap<u32, RpcResponse<Bytes>> = match responses {
+            Ok(responses) => responses
+                .into_iter()
+                .map(|response| (response.id, response))
+                .collect(),
+            Err(error) => {
+                results.extend(chunk.iter().map(|_| Err(error.to_string())));
+                continue;
+            }
+        };
+        //responses of a batch can come in any order
+        for index in 0..chunk.len() {
+            let result = match responses.remove(&((first_id + index) as u32)) {
+                Some(RpcResponse {
+                    result: Some(result),
+                    ..
+                }) => Ok(result),
+                Some(RpcResponse {
+                    error: Some(error), ..
+                }) => Err(format!("rpc error {}: {}", error.code, error.message)),
+                _ => Err("missing rpc response".to_string()),
+            };
+            results.push(result);
+        }
+    }
+    results
+}
@@ -0,0 +1,113 @@
+use std::{error::Error, fmt::Display, fs, str::FromStr};
+
+use clap::{Arg, ArgAction, ArgMatches};
+use serde::de::DeserializeOwned;
+
+use crate::helpers::{
+    fees::fee_model::FeeModels,
+    token_list::{TokenListConfig, TokenListSource},
+};
+
+/// Parse `chain=value` arguments. Ex: `--end-block ethereum=16800000`.
+pub fn parse_chain_value<T>(arg: &str) -> Result<(String, T), String>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let (chain, value) = match arg.split_once('=') {
+        Some(split) => split,
+        None => return Err(format!("expected chain=value, got {arg}")),
+    };
+    match value.trim().parse::<T>() {
+        Ok(value) => Ok((chain.trim().to_string(), value)),
+        Err(error) => Err(format!("invalid value for {chain}: {error}")),
+    }
+}
+
+pub fn read_json_file<T: DeserializeOwned>(path: &str) -> Result<T, Box<dyn Error>> {
+    let file = fs::read_to_string(path)?;
+    Ok(serde_json::from_str(&file)?)
+}
+
+/// Parse `chain:key=value` arguments. Ex: `--sim-alloc polygon:0xc4e5...=100`.
+pub fn parse_chain_key_value<T>(arg: &str) -> Result<(String, String, T), String>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let (chain_key, value) = parse_chain_value::<T>(arg)?;
+    match chain_key.split_once(':') {
+        Some((chain, key)) => Ok((chain.to_string(), key.to_lowercase(), value)),
+        None => Err(format!("expected chain:key=value, got {arg}")),
+    }
+}
+
+/// Token list selection shared by the commands querying volume.
+pub fn token_list_args() -> Vec<Arg> {
+    vec![
+        Arg::new("token-list")
+            .help("Token list filtering pairs: remote helper (bundled snapshot fallback), a Uniswap format --token-list-file, the bundled snapshot or none for every pair. The snapshot only has a few core tokens per chain, totals filtered with it are much lower.")
+            .long("token-list")
+            .value_parser(["remote", "file", "snapshot", "none"])
+            .default_value("remote"),
+        Arg::new("token-list-file")
+            .help("Uniswap format tokenlist.json used with --token-list file.")
+            .long("token-list-file")
+            .required_if_eq("token-list", "file"),
+        Arg::new("token-allow")
+            .help("Add a token to the list of a chain. Ex: polygon=0x2791...")
+            .long("token-allow")
+            .action(ArgAction::Append)
+            .value_parser(parse_chain_value::<String>),
+        Arg::new("token-deny")
+            .help("Drop pairs with a token on a chain, even without token list. Ex: polygon=0x2791...")
+            .long("token-deny")
+            .action(ArgAction::Append)
+            .value_parser(parse_chain_value::<String>),
+    ]
+}
+
+pub fn parse_token_list_config(params: &ArgMatches) -> TokenListConfig {
+    let source = match params.get_one::<String>("token-list").unwrap().as_str() {
+        "file" => {
+            TokenListSource::File(params.get_one::<String>("token-list-file").unwrap().clone())
+        } //required by clap
+        "snapshot" => TokenListSource::Snapshot,
+        "none" => TokenListSource::None,
+        _ => TokenListSource::Remote, //default to remote
+    };
+    let chain_tokens = |id: &str| -> Vec<(String, String)> {
+        match params.get_many::<(String, String)>(id) {
+            Some(values) => values
+                .map(|(chain, token)| (chain.clone(), token.to_lowercase()))
+                .collect(),
+            None => vec![],
+        }
+    };
+    TokenListConfig {
+        source,
+        allow: chain_tokens("token-allow"),
+        deny: chain_tokens("token-deny"),
+    }
+}
+
+/// Treasury fee share models shared by the commands computing revenues.
+pub fn fee_models_arg() -> Arg {
+    Arg::new("fee-models")
+        .help("JSON array of treasury fee share models {name, share, protocol_fee, chains, protocols, swap_fees, from, until}, the first matching a pair applies. V3 pools keep their contract protocol fee unless a model lists V3 in its protocols.")
+        .long("fee-models")
+}
+
+//None when the file can't be read, the error being already printed
+pub fn parse_fee_models(params: &ArgMatches) -> Option<FeeModels> {
+    match params.get_one::<String>("fee-models") {
+        Some(path) => match FeeModels::from_file(path) {
+            Ok(fee_models) => Some(fee_models),
+            Err(error) => {
+                eprintln!("Error while reading fee models: {:#?}", error);
+                None
+            }
+        },
+        None => Some(FeeModels::default()),
+    }
+}
@@ -0,0 +1,122 @@
+use clap::{Arg, ArgGroup, ArgMatches, Command};
+use cli_table::{print_stdout, Cell, CellStruct, Table};
+
+use crate::{
+    helpers::{
+        block::{
+            block_rpc::query_block_by_number,
+            block_timestamp::{query_block_at, query_multichain_block_at, Block, BlockSource},
+        },
+        time::{format_delta, format_timestamp, parse_time},
+    },
+    network::NETWORKS,
+};
+
+pub fn command() -> Command {
+    let network_arg = Arg::new("network")
+        .help("Network to query, all networks if omitted. Ex: ethereum.")
+        .short('n')
+        .long("network");
+    let at_arg = Arg::new("at")
+        .help("UTC time to resolve. Ex: \"2026-10-01 00:00\", 2026-10-01 or a unix timestamp.")
+        .long("at");
+    let number_arg = Arg::new("number")
+        .help("Block number to resolve to a timestamp, requires --network.")
+        .long("number")
+        .requires("network")
+        .value_parser(clap::value_parser!(u64));
+    let block_source_arg = Arg::new("block-source")
+        .help("Where to resolve blocks from: subgraph (rpc fallback) or rpc (cross-checked with subgraph).")
+        .long("block-source")
+        .value_parser(["subgraph", "rpc"])
+        .default_value("subgraph");
+
+    Command::new("block")
+        .about("Convert a time to the last block mined before it on every chain, or a block to its time.")
+        .arg(network_arg)
+        .arg(at_arg)
+        .arg(number_arg)
+        .arg(block_source_arg)
+        .group(ArgGroup::new("lookup").args(["at", "number"]).required(true))
+}
+
+pub fn execute(params: &ArgMatches) {
+    let network = params.get_one::<String>("network");
+
+    if let Some(number) = params.get_one::<u64>("number") {
+        let chain = network.unwrap(); //required by clap
+        match query_block_by_number(chain, *number) {
+            Ok(block) => print_block_number(chain, block),
+            Err(error) => eprintln!("Error while querying block: {:#?}", error),
+        }
+        return;
+    }
+
+    let time = params.get_one::<String>("at").unwrap(); //required by clap when no --number
+    let timestamp = match parse_time(time) {
+        Some(timestamp) => timestamp,
+        None => {
+            eprintln!(
+                "Could not parse time {time}, use a unix timestamp or YYYY-MM-DD [HH:MM[:SS]]."
+            );
+            return;
+        }
+    };
+    let block_source =
+        BlockSource::parse(params.get_one::<String>("block-source").unwrap()).unwrap(); //checked by clap
+
+    if let Some(chain) = network {
+        match query_block_at(chain, timestamp, block_source) {
+            Ok(block) => print_blocks_at(timestamp, vec![(chain.clone(), block)]),
+            Err(error) => eprintln!("Error while querying block: {:#?}", error),
+        }
+    } else {
+        let chains: Vec<String> = NETWORKS.keys().map(|chain| chain.to_string()).collect();
+        let mut blocks: Vec<(String, Block)> =
+            query_multichain_block_at(chains, timestamp, block_source)
+                .into_iter()
+                .collect();
+        blocks.sort_by(|a, b| a.0.cmp(&b.0));
+        print_blocks_at(timestamp, blocks);
+    }
+}
+
+fn print_blocks_at(timestamp: u64, blocks: Vec<(String, Block)>) {
+    println!("Last blocks before {}", format_timestamp(timestamp));
+    let blocks_table: Vec<Vec<CellStruct>> = blocks
+        .iter()
+        .map(|(chain, block)| {
+            vec![
+                chain.as_str().cell(),
+                block.number.cell(),
+                format_timestamp(block.timestamp).cell(),
+                format_delta(block.timestamp as i64 - timestamp as i64).cell(),
+            ]
+        })
+        .collect();
+    let blocks_table = blocks_table.table().title(vec![
+        "Chain".cell(),
+        "Block".cell(),
+        "Timestamp".cell(),
+        "Delta".cell(),
+    ]);
+
+    print_stdout(blocks_table).expect("Error while pinting result.");
+}
+
+fn print_block_number(chain: &str, block: Block) {
+    let blocks_table = vec![vec![
+        chain.cell(),
+        block.number.cell(),
+        format_timestamp(block.timestamp).cell(),
+        block.timestamp.cell(),
+    ]];
+    let blocks_table = blocks_table.table().title(vec![
+        "Chain".cell(),
+        "Block".cell(),
+        "Timestamp".cell(),
+        "Unix".cell(),
+    ]);
+
+    print_stdout(blocks_table).expect("Error while pinting result.");
+}
@@ -0,0 +1,150 @@
+use std::cmp::Ordering;
+
+use clap::{Arg, ArgAction, ArgMatches, Command};
+use cli_table::{print_stdout, Cell, CellStruct, Table};
+
+use crate::{
+    helpers::{
+        block::block_timestamp::BlockSource,
+        farm::{
+            apr::{query_farms_apr, query_farms_apr_multichain, FarmsApr},
+            pools_with_sushi::{Pool, PoolApr},
+        },
+        price::query_sushi_price,
+        volume::period_volume::Period,
+    },
+    network::{LEGACY_SUBGRAPH, MINICHEF_SUBGRAPH},
+    scli::args::{fee_models_arg, parse_fee_models},
+};
+
+pub fn command() -> Command {
+    let network_arg = Arg::new("network")
+        .help("Network to query, all chains with farms if not set. Ex: polygon.")
+        .short('n')
+        .long("network");
+    let days_arg = Arg::new("days")
+        .help("Days of fees the fee APR is computed on.")
+        .short('d')
+        .long("days")
+        .default_value("7")
+        .value_parser(clap::value_parser!(u32).range(1..=30));
+    let onchain_arg = Arg::new("onchain")
+        .help(
+            "Read staked LP with balanceOf calls on the chefs instead of the subgraph slpBalance.",
+        )
+        .long("onchain")
+        .action(ArgAction::SetTrue);
+    let sort_by_arg = Arg::new("sort-by")
+        .help("Rank farms by staked TVL, reward APR or fee APR.")
+        .long("sort-by")
+        .value_parser(["tvl", "reward", "fee"])
+        .default_value("tvl");
+
+    Command::new("apr")
+        .about("Print staked LP TVL, SUSHI reward APR and fee APR of every farm.")
+        .arg(network_arg)
+        .arg(days_arg)
+        .arg(onchain_arg)
+        .arg(sort_by_arg)
+        .arg(fee_models_arg())
+}
+
+pub fn execute(params: &ArgMatches) {
+    let period = Period {
+        days: *params.get_one::<u32>("days").unwrap(), //default to 7
+        block_source: BlockSource::Subgraph,
+        token_list: Default::default(),
+    };
+    let onchain = params.get_flag("onchain");
+    let fee_models = match parse_fee_models(params) {
+        Some(fee_models) => fee_models,
+        None => return,
+    };
+    let sushi_price = match query_sushi_price() {
+        Ok(price) => price,
+        Err(error) => {
+            eprintln!("Error while querying sushi price: {:#?}", error);
+            return;
+        }
+    };
+
+    let farms: Vec<FarmsApr> = match params.get_one::<String>("network") {
+        Some(chain) => {
+            match query_farms_apr(chain.clone(), &period, sushi_price, &fee_models, onchain) {
+                Ok(farms) => vec![farms],
+                Err(error) => {
+                    eprintln!("Error while querying farms apr: {:#?}", error);
+                    return;
+                }
+            }
+        }
+        None => {
+            let mut chains: Vec<String> = MINICHEF_SUBGRAPH
+                .keys()
+                .filter(|chain| LEGACY_SUBGRAPH.contains_key(chain))
+                .map(|chain| chain.to_string())
+                .collect();
+            chains.push("ethereum".to_string());
+            query_farms_apr_multichain(chains, period, sushi_price, &fee_models, onchain)
+        }
+    };
+
+    let mut pools: Vec<(&FarmsApr, &Pool)> = farms
+        .iter()
+        .flat_map(|farms| farms.minichef.pools.values().map(move |pool| (farms, pool)))
+        .collect();
+    let sort_by = params.get_one::<String>("sort-by").unwrap().as_str(); //default to tvl
+    let key = |apr: Option<PoolApr>| match (apr, sort_by) {
+        (Some(apr), "reward") => apr.reward_apr,
+        (Some(apr), "fee") => apr.fee_apr,
+        (Some(apr), _) => apr.staked_tvl_usd,
+        (None, _) => f64::MIN,
+    };
+    pools.sort_by(|a, b| {
+        if key(a.1.apr) > key(b.1.apr) {
+            return Ordering::Less;
+        }
+        Ordering::Greater
+    });
+
+    let percent = |apr: f64| format!("{:.2} %", apr * 100.0);
+    let table: Vec<Vec<CellStruct>> = pools
+        .iter()
+        .map(|(farms, pool)| {
+            let name = farms
+                .pairs
+                .get(&pool.id)
+                .map_or("?", |pair| pair.name.as_str());
+            let (tvl, reward_apr, fee_apr) = match pool.apr {
+                Some(apr) => (
+                    format!("{} $", apr.staked_tvl_usd.round()),
+                    percent(apr.reward_apr),
+                    percent(apr.fee_apr),
+                ),
+                None => ("-".to_string(), "-".to_string(), "-".to_string()),
+            };
+            vec![
+                farms.chain.as_str().cell(),
+                pool.pid.cell(),
+                name.cell(),
+                pool.id.as_str().cell(),
+                pool.sushi_per_day.round().cell(),
+                tvl.cell(),
+                reward_apr.cell(),
+                fee_apr.cell(),
+            ]
+        })
+        .collect();
+    let table = table.table().title(vec![
+        "Chain".cell(),
+        "Pid".cell(),
+        "Pair".cell(),
+        "Address".cell(),
+        "SUSHI/day".cell(),
+        "Staked TVL".cell(),
+        "Reward APR".cell(),
+        "Fee APR".cell(),
+    ]);
+
+    print_stdout(table).expect("Error while pinting result.");
+}
@@ -0,0 +1,192 @@
+use std::cmp::Ordering;
+
+use clap::{Arg, ArgAction, ArgMatches, Command};
+use cli_table::{print_stdout, Cell, CellStruct, Table};
+
+use crate::{
+    helpers::{
+        block::block_timestamp::BlockSource,
+        farm::audit::{audit_farms, audit_farms_multichain, AuditFlag, AuditThresholds, FarmAudit},
+        price::query_sushi_price,
+    },
+    network::{LEGACY_SUBGRAPH, MINICHEF_SUBGRAPH},
+    scli::args::{fee_models_arg, parse_fee_models},
+};
+
+pub fn command() -> Command {
+    let network_arg = Arg::new("network")
+        .help("Network to audit, all chains with farms if not set. Ex: polygon.")
+        .short('n')
+        .long("network");
+    let days_arg = Arg::new("days")
+        .help("Days in the past the farms are judged on.")
+        .short('d')
+        .long("days")
+        .default_value("7")
+        .value_parser(clap::value_parser!(u32).range(1..=30));
+    let min_volume_arg = Arg::new("min-volume")
+        .help("Volume per day in USD under which a farm is considered dead.")
+        .long("min-volume")
+        .default_value("1000")
+        .value_parser(clap::value_parser!(f64));
+    let min_fee_ratio_arg = Arg::new("min-fee-ratio")
+        .help("Min treasury fees as a fraction of the emissions spent on the farm.")
+        .long("min-fee-ratio")
+        .default_value("0.1")
+        .value_parser(clap::value_parser!(f64));
+    let max_liquidity_drop_arg = Arg::new("max-liquidity-drop")
+        .help("Liquidity drop over the period, as a fraction, above which liquidity is collapsing.")
+        .long("max-liquidity-drop")
+        .default_value("0.5")
+        .value_parser(clap::value_parser!(f64));
+    let all_arg = Arg::new("all")
+        .help("Also list farms without any flag.")
+        .long("all")
+        .action(ArgAction::SetTrue);
+
+    Command::new("audit")
+        .about("Flag dead and underperforming farms and rank them by SUSHI freed for reallocation if cut.")
+        .arg(network_arg)
+        .arg(days_arg)
+        .arg(min_volume_arg)
+        .arg(min_fee_ratio_arg)
+        .arg(max_liquidity_drop_arg)
+        .arg(fee_models_arg())
+        .arg(all_arg)
+}
+
+fn flag_label(flag: &AuditFlag) -> &'static str {
+    match flag {
+        AuditFlag::NotAPair => "not a pair",
+        AuditFlag::UnparsablePair => "unparsable pair",
+        AuditFlag::NoVolume => "no volume",
+        AuditFlag::LowFees => "low fees",
+        AuditFlag::LiquidityCollapse => "liquidity collapse",
+    }
+}
+
+pub fn execute(params: &ArgMatches) {
+    let days = *params.get_one::<u32>("days").unwrap(); //default to 7
+    let thresholds = AuditThresholds {
+        min_volume_per_day: *params.get_one::<f64>("min-volume").unwrap(),
+        min_fee_ratio: *params.get_one::<f64>("min-fee-ratio").unwrap(),
+        max_liquidity_drop: *params.get_one::<f64>("max-liquidity-drop").unwrap(),
+    }; //all with default values
+    let fee_models = match parse_fee_models(params) {
+        Some(fee_models) => fee_models,
+        None => return,
+    };
+    let sushi_price = match query_sushi_price() {
+        Ok(price) => price,
+        Err(error) => {
+            eprintln!("Error while querying sushi price: {:#?}", error);
+            return;
+        }
+    };
+
+    let mut audits: Vec<FarmAudit> = match params.get_one::<String>("network") {
+        Some(chain) => match audit_farms(
+            chain.clone(),
+            days,
+            BlockSource::Subgraph,
+            thresholds,
+            &fee_models,
+            sushi_price,
+        ) {
+            Ok(audits) => audits,
+            Err(error) => {
+                eprintln!("Error while auditing farms: {:#?}", error);
+                return;
+            }
+        },
+        None => {
+            let mut chains: Vec<String> = MINICHEF_SUBGRAPH
+                .keys()
+                .filter(|chain| LEGACY_SUBGRAPH.contains_key(chain))
+                .map(|chain| chain.to_string())
+                .collect();
+            chains.push("ethereum".to_string());
+            audit_farms_multichain(
+                chains,
+                days,
+                BlockSource::Subgraph,
+                thresholds,
+                &fee_models,
+                sushi_price,
+            )
+            .into_values()
+            .flatten()
+            .collect()
+        }
+    };
+
+    if !params.get_flag("all") {
+        audits.retain(|audit| !audit.flags.is_empty());
+    }
+    //cut-list, biggest emissions freed first
+    audits.sort_by(|a, b| {
+        if a.sushi_per_day > b.sushi_per_day {
+            return Ordering::Less;
+        }
+        Ordering::Greater
+    });
+
+    let liquidity = |liquidity: Option<f64>| match liquidity {
+        Some(liquidity) => format!("{} $", liquidity.round()),
+        None => "-".to_string(),
+    };
+    let table: Vec<Vec<CellStruct>> = audits
+        .iter()
+        .map(|audit| {
+            vec![
+                audit.chain.as_str().cell(),
+                audit.pid.cell(),
+                audit.name.as_deref().unwrap_or("?").cell(),
+                audit.pair.as_str().cell(),
+                format!("{} $", audit.volume_usd.round()).cell(),
+                format!("{} $", audit.fees_usd.round()).cell(),
+                format!("{} $", audit.spent_usd.round()).cell(),
+                liquidity(audit.start_liquidity_usd).cell(),
+                liquidity(audit.end_liquidity_usd).cell(),
+                //only flagged farms are proposed for a cut
+                match audit.flags.is_empty() {
+                    true => "".cell(),
+                    false => audit.sushi_per_day.round().cell(),
+                },
+                audit
+                    .flags
+                    .iter()
+                    .map(flag_label)
+                    .collect::<Vec<&str>>()
+                    .join(", ")
+                    .cell(),
+            ]
+        })
+        .collect();
+    let table = table.table().title(vec![
+        "Chain".cell(),
+        "Pid".cell(),
+        "Pair".cell(),
+        "Address".cell(),
+        "Volume".cell(),
+        "Fees (to Treasury)".cell(),
+        "Spent".cell(),
+        "Start liquidity".cell(),
+        "Liquidity".cell(),
+        "SUSHI/day freed".cell(),
+        "Flags".cell(),
+    ]);
+
+    print_stdout(table).expect("Error while pinting result.");
+
+    let freed: f64 = audits
+        .iter()
+        .filter(|audit| !audit.flags.is_empty())
+        .map(|audit| audit.sushi_per_day)
+        .sum();
+    println!(
+        "Cutting every flagged farm frees {} SUSHI/day ({} $ per day) for reallocation, it only saves emissions if the chef emission is lowered too.",
+        freed.round(),
+        (freed * sushi_price).round()
+    );
+}
@@ -0,0 +1,131 @@
+use std::cmp::Ordering;
+
+use clap::{Arg, ArgMatches, Command};
+use cli_table::{print_stdout, Cell, CellStruct, Table};
+
+use crate::{
+    helpers::{
+        block::block_timestamp::BlockSource,
+        farm::candidates::{find_candidates, find_candidates_multichain, Candidate},
+        volume::period_volume::Period,
+    },
+    network::{LEGACY_SUBGRAPH, MINICHEF_SUBGRAPH},
+    scli::args::{fee_models_arg, parse_fee_models},
+};
+
+pub fn command() -> Command {
+    let network_arg = Arg::new("network")
+        .help("Network to look at, all chains with farms if not set. Ex: polygon.")
+        .short('n')
+        .long("network");
+    let days_arg = Arg::new("days")
+        .help("Days in the past to compute fees on, growth compares with the period before.")
+        .short('d')
+        .long("days")
+        .default_value("7")
+        .value_parser(clap::value_parser!(u32).range(1..=30));
+    let min_fees_arg = Arg::new("min-fees")
+        .help("Min treasury fees in USD over the period for a pair to be listed.")
+        .long("min-fees")
+        .default_value("100")
+        .value_parser(clap::value_parser!(f64));
+    let sort_by_arg = Arg::new("sort-by")
+        .help("Rank candidates by treasury fees, volume growth or liquidity.")
+        .long("sort-by")
+        .value_parser(["fees", "growth", "liquidity"])
+        .default_value("fees");
+    let limit_arg = Arg::new("limit")
+        .help("Max number of candidates to print.")
+        .long("limit")
+        .default_value("20")
+        .value_parser(clap::value_parser!(usize));
+
+    Command::new("candidates")
+        .about("Rank pairs earning fees without a farm, to nominate them for incentives.")
+        .arg(network_arg)
+        .arg(days_arg)
+        .arg(min_fees_arg)
+        .arg(sort_by_arg)
+        .arg(limit_arg)
+        .arg(fee_models_arg())
+}
+
+pub fn execute(params: &ArgMatches) {
+    let period = Period {
+        days: *params.get_one::<u32>("days").unwrap(), //default to 7
+        block_source: BlockSource::Subgraph,
+        token_list: Default::default(),
+    };
+    let min_fees = *params.get_one::<f64>("min-fees").unwrap(); //default to 100
+    let fee_models = match parse_fee_models(params) {
+        Some(fee_models) => fee_models,
+        None => return,
+    };
+
+    let mut candidates: Vec<Candidate> = match params.get_one::<String>("network") {
+        Some(chain) => match find_candidates(chain.clone(), &period, &fee_models, min_fees) {
+            Ok(candidates) => candidates,
+            Err(error) => {
+                eprintln!("Error while looking for farm candidates: {:#?}", error);
+                return;
+            }
+        },
+        None => {
+            let mut chains: Vec<String> = MINICHEF_SUBGRAPH
+                .keys()
+                .filter(|chain| LEGACY_SUBGRAPH.contains_key(chain))
+                .map(|chain| chain.to_string())
+                .collect();
+            chains.push("ethereum".to_string());
+            find_candidates_multichain(chains, period, &fee_models, min_fees)
+        }
+    };
+
+    let sort_by = params.get_one::<String>("sort-by").unwrap().as_str(); //default to fees
+    let key = |candidate: &Candidate| match sort_by {
+        "growth" => candidate.volume_growth().unwrap_or(f64::MIN),
+        "liquidity" => candidate.liquidity_usd.unwrap_or_default(),
+        _ => candidate.fees_usd,
+    };
+    candidates.sort_by(|a, b| {
+        if key(a) > key(b) {
+            return Ordering::Less;
+        }
+        Ordering::Greater
+    });
+    candidates.truncate(*params.get_one::<usize>("limit").unwrap()); //default to 20
+
+    let table: Vec<Vec<CellStruct>> = candidates
+        .iter()
+        .map(|candidate| {
+            vec![
+                candidate.chain.as_str().cell(),
+                candidate.name.as_str().cell(),
+                candidate.pair.as_str().cell(),
+                format!("{} $", candidate.volume_usd.round()).cell(),
+                format!("{} $", candidate.fees_usd.round()).cell(),
+                match candidate.volume_growth() {
+                    Some(growth) => format!("{:+.1} %", growth * 100.0),
+                    None => "new".to_string(),
+                }
+                .cell(),
+                match candidate.liquidity_usd {
+                    Some(liquidity) => format!("{} $", liquidity.round()),
+                    None => "-".to_string(),
+                }
+                .cell(),
+            ]
+        })
+        .collect();
+    let table = table.table().title(vec![
+        "Chain".cell(),
+        "Pair".cell(),
+        "Address".cell(),
+        "Volume".cell(),
+        "Fees (to Treasury)".cell(),
+        "Volume growth".cell(),
+        "Liquidity".cell(),
+    ]);
+
+    print_stdout(table).expect("Error while pinting result.");
+}
@@ -0,0 +1,259 @@
+use std::{collections::HashMap, fs, path::Path, str::FromStr};
+
+use clap::{Arg, ArgAction, ArgMatches, Command};
+use cli_table::{print_stdout, Cell, CellStruct, Table};
+use ethers::types::{Address, U256};
+use serde::Deserialize;
+
+use crate::{
+    helpers::{
+        block::block_timestamp::now,
+        farm::{
+            chef_calls::{
+                encode_mass_update_pools, encode_set, encode_set_sushi_per_second, Chef, ChefCall,
+                ChefKind, SafeBatch,
+            },
+            pools_with_sushi::{
+                query_masterchef_active_pids, query_masterchefv2_active_pids,
+                query_pools_with_sushi,
+            },
+        },
+    },
+    scli::args::{parse_chain_value, read_json_file},
+};
+
+/// Alloc point of a pid, optionally with a new rewarder.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(untagged)]
+enum AllocTarget {
+    AllocPoint(u32),
+    WithRewarder {
+        alloc_point: u32,
+        rewarder: Option<String>,
+    },
+}
+
+impl AllocTarget {
+    fn alloc_point(&self) -> u32 {
+        match self {
+            AllocTarget::AllocPoint(alloc_point) => *alloc_point,
+            AllocTarget::WithRewarder { alloc_point, .. } => *alloc_point,
+        }
+    }
+
+    fn rewarder(&self) -> Result<Option<Address>, String> {
+        match self {
+            AllocTarget::WithRewarder {
+                rewarder: Some(rewarder),
+                ..
+            } => match Address::from_str(rewarder) {
+                Ok(rewarder) => Ok(Some(rewarder)),
+                Err(error) => Err(format!("invalid rewarder {rewarder}: {error}")),
+            },
+            _ => Ok(None),
+        }
+    }
+}
+
+/// Targets of a chef, pids alone or with a new MiniChef emission as written by farms optimize.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(untagged)]
+enum ChefTargets {
+    WithEmission {
+        pools: HashMap<String, AllocTarget>,
+        sushi_per_second: Option<String>, //in wei
+    },
+    Pools(HashMap<String, AllocTarget>),
+}
+
+impl ChefTargets {
+    fn pools(&mut self) -> &mut HashMap<String, AllocTarget> {
+        match self {
+            ChefTargets::WithEmission { pools, .. } => pools,
+            ChefTargets::Pools(pools) => pools,
+        }
+    }
+
+    fn sushi_per_second(&self) -> Result<Option<U256>, String> {
+        match self {
+            ChefTargets::WithEmission {
+                sushi_per_second: Some(sushi_per_second),
+                ..
+            } => match U256::from_dec_str(sushi_per_second) {
+                Ok(sushi_per_second) => Ok(Some(sushi_per_second)),
+                Err(error) => Err(format!(
+                    "invalid sushi_per_second {sushi_per_second}: {error}"
+                )),
+            },
+            _ => Ok(None),
+        }
+    }
+}
+
+pub fn command() -> Command {
+    let file_arg = Arg::new("file")
+        .help("JSON alloc map per chef, chef being a network or masterchef/masterchefv2. Ex: {\"polygon\": {\"12\": 100, \"3\": {\"alloc_point\": 0, \"rewarder\": \"0x...\"}}}, a MiniChef emission can be set with {\"polygon\": {\"pools\": {...}, \"sushi_per_second\": \"...\"}}")
+        .short('f')
+        .long("file");
+    let network_arg = Arg::new("network")
+        .help("Chef of the --set values, a network or masterchef/masterchefv2.")
+        .short('n')
+        .long("network")
+        .requires("set");
+    let set_arg = Arg::new("set")
+        .help("New alloc point of a pid. Ex: 12=100")
+        .long("set")
+        .requires("network")
+        .action(ArgAction::Append)
+        .value_parser(parse_chain_value::<u32>);
+    let out_dir_arg = Arg::new("out-dir")
+        .help("Directory where the Gnosis Safe transaction builder files are written.")
+        .long("out-dir")
+        .default_value(".");
+
+    Command::new("encode")
+        .about("Encode chef set()/massUpdatePools() calldata and Gnosis Safe batches for new alloc points.")
+        .arg(file_arg)
+        .arg(network_arg)
+        .arg(set_arg)
+        .arg(out_dir_arg)
+}
+
+fn parse_alloc_map(params: &ArgMatches) -> Result<HashMap<String, ChefTargets>, String> {
+    let mut alloc_map: HashMap<String, ChefTargets> = match params.get_one::<String>("file") {
+        Some(path) => match read_json_file(path) {
+            Ok(alloc_map) => alloc_map,
+            Err(error) => return Err(format!("could not read {path}: {error}")),
+        },
+        None => HashMap::new(),
+    };
+    if let (Some(chef), Some(values)) = (
+        params.get_one::<String>("network"),
+        params.get_many::<(String, u32)>("set"),
+    ) {
+        let chef_allocs = alloc_map
+            .entry(chef.clone())
+            .or_insert_with(|| ChefTargets::Pools(HashMap::new()))
+            .pools();
+        for (pid, alloc_point) in values {
+            chef_allocs.insert(pid.clone(), AllocTarget::AllocPoint(*alloc_point));
+        }
+    }
+    if alloc_map.is_empty() {
+        return Err("nothing to encode, use --file or --network with --set".to_string());
+    }
+    Ok(alloc_map)
+}
+
+fn encode_chef_calls(chef: &Chef, chef_targets: &mut ChefTargets) -> Result<Vec<ChefCall>, String> {
+    let sushi_per_second = chef_targets.sushi_per_second()?;
+    let mut targets: Vec<(u32, &AllocTarget)> = vec![];
+    for (pid, target) in chef_targets.pools().iter() {
+        match pid.parse::<u32>() {
+            Ok(pid) => targets.push((pid, target)),
+            Err(error) => return Err(format!("invalid pid {pid}: {error}")),
+        }
+    }
+    targets.sort_by_key(|(pid, _)| *pid);
+
+    //update every active pool before changing the total alloc point, or their pending
+    //rewards get computed with the new total
+    let active_pids = match chef.kind {
+        ChefKind::MasterChef => query_masterchef_active_pids(),
+        ChefKind::MasterChefV2 => query_masterchefv2_active_pids(),
+        ChefKind::MiniChef => query_pools_with_sushi(chef.chain.clone())
+            .map(|minichef| minichef.pools.values().map(|pool| pool.pid).collect()),
+    };
+    let mut update_pids: Vec<u32> = match active_pids {
+        Ok(pids) => pids,
+        Err(error) => return Err(format!("could not fetch active pools: {error:?}")),
+    };
+    update_pids.extend(targets.iter().map(|(pid, _)| *pid));
+    update_pids.sort();
+    update_pids.dedup();
+
+    let mut calls = vec![encode_mass_update_pools(chef, &update_pids)];
+    if let Some(sushi_per_second) = sushi_per_second {
+        match encode_set_sushi_per_second(chef, sushi_per_second) {
+            Some(call) => calls.push(call),
+            None => return Err("sushi_per_second can only be set on a MiniChef".to_string()),
+        }
+    }
+    for (pid, target) in targets {
+        match encode_set(chef, pid, target.alloc_point(), target.rewarder()?) {
+            Some(call) => calls.push(call),
+            None => return Err(format!("pid {pid}: MasterChef v1 pools have no rewarder")),
+        }
+    }
+    Ok(calls)
+}
+
+pub fn execute(params: &ArgMatches) {
+    let mut alloc_map = match parse_alloc_map(params) {
+        Ok(alloc_map) => alloc_map,
+        Err(error) => {
+            eprintln!("Error while reading alloc points: {error}");
+            return;
+        }
+    };
+    let out_dir = Path::new(params.get_one::<String>("out-dir").unwrap()); //default to .
+
+    let mut keys: Vec<String> = alloc_map.keys().cloned().collect();
+    keys.sort();
+    for key in keys {
+        let key = &key;
+        let chef = match Chef::from_key(key) {
+            Some(chef) => chef,
+            None => {
+                eprintln!("Unknown chef {key}, use a MiniChef network or masterchef/masterchefv2.");
+                continue;
+            }
+        };
+        let calls = match encode_chef_calls(&chef, alloc_map.get_mut(key).unwrap()) {
+            Ok(calls) => calls,
+            Err(error) => {
+                eprintln!("Error while encoding {key} calls: {error}");
+                continue;
+            }
+        };
+
+        println!("{key} ({}):", chef.address);
+        print_calls(&calls);
+
+        let batch = match SafeBatch::new(&chef, &calls, now()) {
+            Some(batch) => batch,
+            None => {
+                eprintln!(
+                    "Unknown chain id for {}, no Safe batch written.",
+                    chef.chain
+                );
+                continue;
+            }
+        };
+        let path = out_dir.join(format!("safe-batch-{key}.json"));
+        let written = serde_json::to_string_pretty(&batch)
+            .map_err(|error| error.to_string())
+            .and_then(|batch| fs::write(&path, batch).map_err(|error| error.to_string()));
+        match written {
+            Ok(_) => println!("Safe batch written to {}", path.display()),
+            Err(error) => eprintln!("Error while writing {}: {error}", path.display()),
+        }
+    }
+}
+
+fn print_calls(calls: &[ChefCall]) {
+    let calls_table: Vec<Vec<CellStruct>> = calls
+        .iter()
+        .map(|call| {
+            vec![
+                call.description.as_str().cell(),
+                call.data.to_string().cell(),
+            ]
+        })
+        .collect();
+    let calls_table = calls_table
+        .table()
+        .title(vec!["Call".cell(), "Calldata".cell()]);
+
+    print_stdout(calls_table).expect("Error while pinting result.");
+}
@@ -0,0 +1,49 @@
+use clap::{ArgMatches, Command};
+
+mod apr;
+mod audit;
+mod candidates;
+mod encode;
+mod optimize;
+mod reconcile;
+mod runway;
+
+pub fn command() -> Command {
+    Command::new("farms")
+        .about("Inspect and plan MasterChef/MiniChef farms.")
+        .subcommand_required(true)
+        .subcommand(apr::command())
+        .subcommand(audit::command())
+        .subcommand(candidates::command())
+        .subcommand(encode::command())
+        .subcommand(optimize::command())
+        .subcommand(reconcile::command())
+        .subcommand(runway::command())
+}
+
+pub fn execute(params: &ArgMatches) {
+    match params.subcommand() {
+        Some(("apr", params)) => {
+            apr::execute(params);
+        }
+        Some(("audit", params)) => {
+            audit::execute(params);
+        }
+        Some(("candidates", params)) => {
+            candidates::execute(params);
+        }
+        Some(("encode", params)) => {
+            encode::execute(params);
+        }
+        Some(("optimize", params)) => {
+            optimize::execute(params);
+        }
+        Some(("reconcile", params)) => {
+            reconcile::execute(params);
+        }
+        Some(("runway", params)) => {
+            runway::execute(params);
+        }
+        _ => println!("none"),
+    }
+}
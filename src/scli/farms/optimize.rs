@@ -0,0 +1,250 @@
+use std::{
+    cmp::Ordering,
+    collections::{BTreeMap, HashMap},
+    fs,
+};
+
+use clap::{Arg, ArgMatches, Command};
+use cli_table::{print_stdout, Cell, CellStruct, Table};
+use serde_json::json;
+
+use crate::helpers::{
+    block::block_timestamp::{now, BlockSource},
+    farm::{
+        optimizer::{optimize_allocations, AllocPlan, OptimizerConstraints},
+        pools_with_sushi::query_pools_with_sushi,
+    },
+    price::query_sushi_price,
+    volume::period_volume::{query_period_volume, Period},
+};
+use crate::scli::args::{fee_models_arg, parse_fee_models};
+
+pub fn command() -> Command {
+    let network_arg = Arg::new("network")
+        .help("MiniChef network to optimize. Ex: polygon.")
+        .short('n')
+        .long("network")
+        .required(true);
+    let days_arg = Arg::new("days")
+        .help("Days of fees used to estimate each pool revenue.")
+        .short('d')
+        .long("days")
+        .default_value("7")
+        .value_parser(clap::value_parser!(u32).range(1..=30));
+    let budget_arg = Arg::new("budget")
+        .help("Max SUSHI per day to emit on the chain, defaults to the current emission.")
+        .long("budget")
+        .value_parser(clap::value_parser!(f64));
+    let min_alloc_arg = Arg::new("min-alloc")
+        .help("Min alloc point of every unprotected farm.")
+        .long("min-alloc")
+        .default_value("0")
+        .value_parser(clap::value_parser!(u32));
+    let max_alloc_arg = Arg::new("max-alloc")
+        .help("Max alloc point of a single farm.")
+        .long("max-alloc")
+        .value_parser(clap::value_parser!(u32));
+    let protect_arg = Arg::new("protect")
+        .help("Comma separated pair addresses of farms keeping their current alloc point.")
+        .long("protect")
+        .value_delimiter(',');
+    let elasticity_arg = Arg::new("elasticity")
+        .help("How much a pool fees grow with its incentives, 0 = not at all, 1 = linearly.")
+        .long("elasticity")
+        .default_value("0.5")
+        .value_parser(clap::value_parser!(f64));
+    let out_arg = Arg::new("out")
+        .help("Write the changed alloc points, and the new emission if it changes, to a JSON file usable by farms encode --file.")
+        .long("out");
+
+    Command::new("optimize")
+        .about("Propose new alloc points maximising expected net revenue for a SUSHI budget.")
+        .arg(network_arg)
+        .arg(days_arg)
+        .arg(budget_arg)
+        .arg(min_alloc_arg)
+        .arg(max_alloc_arg)
+        .arg(protect_arg)
+        .arg(elasticity_arg)
+        .arg(fee_models_arg())
+        .arg(out_arg)
+}
+
+pub fn execute(params: &ArgMatches) {
+    let chain = params.get_one::<String>("network").unwrap(); //required by clap
+    if chain.contains("ethereum") {
+        eprintln!("Mainnet alloc points are shared with MasterChef v2 and the dummy pools funding other chains, optimize a MiniChef chain instead.");
+        return;
+    }
+    let days = *params.get_one::<u32>("days").unwrap(); //default to 7
+    let fee_models = match parse_fee_models(params) {
+        Some(fee_models) => fee_models,
+        None => return,
+    };
+
+    let sushi_price = match query_sushi_price() {
+        Ok(price) => price,
+        Err(error) => {
+            eprintln!("Error while querying sushi price: {:#?}", error);
+            return;
+        }
+    };
+    let minichef = match query_pools_with_sushi(chain.clone()) {
+        Ok(minichef) => minichef,
+        Err(error) => {
+            eprintln!("Error while querying farms: {:#?}", error);
+            return;
+        }
+    };
+    let period = Period {
+        days,
+        block_source: BlockSource::Subgraph,
+        token_list: Default::default(),
+    };
+    let volume = match query_period_volume(chain.clone(), &period, Default::default()) {
+        Ok(volume) => volume,
+        Err(error) => {
+            eprintln!("Error while querying volume: {:#?}", error);
+            return;
+        }
+    };
+
+    let timestamp = volume.end_timestamp.unwrap_or_else(now);
+    let fees_per_day: HashMap<String, f64> = volume
+        .pairs
+        .values()
+        .map(|pair| {
+            let share = fee_models.model_for(chain, pair, timestamp).share_of(pair);
+            (pair.id.clone(), pair.fees_usd * share / days as f64)
+        })
+        .collect();
+    let names: HashMap<String, String> = volume
+        .pairs
+        .values()
+        .map(|pair| (pair.id.clone(), pair.name.clone()))
+        .collect();
+
+    let constraints = OptimizerConstraints {
+        budget: *params
+            .get_one::<f64>("budget")
+            .unwrap_or(&minichef.sushi_per_day),
+        min_alloc: *params.get_one::<u32>("min-alloc").unwrap(), //default to 0
+        max_alloc: params.get_one::<u32>("max-alloc").copied(),
+        protected: match params.get_many::<String>("protect") {
+            Some(pairs) => pairs.map(|pair| pair.to_lowercase()).collect(),
+            None => vec![],
+        },
+        elasticity: *params.get_one::<f64>("elasticity").unwrap(), //default to 0.5
+    };
+
+    match optimize_allocations(&minichef, &fees_per_day, sushi_price, &constraints) {
+        Ok(plan) => {
+            if let Some(path) = params.get_one::<String>("out") {
+                write_alloc_map(path, chain, &plan);
+            }
+            print_plan(plan, &names, sushi_price, &constraints)
+        }
+        Err(error) => eprintln!("Error while optimizing alloc points: {:#?}", error),
+    }
+}
+
+fn write_alloc_map(path: &str, chain: &str, plan: &AllocPlan) {
+    let allocs: BTreeMap<String, u32> = plan
+        .proposals
+        .iter()
+        .filter(|proposal| proposal.proposed_alloc != proposal.current_alloc)
+        .map(|proposal| (proposal.pid.to_string(), proposal.proposed_alloc))
+        .collect();
+    let targets = match plan.sushi_per_second() {
+        Some(sushi_per_second) => json!({
+            "pools": allocs,
+            "sushi_per_second": sushi_per_second.to_string(),
+        }),
+        None => json!(allocs),
+    };
+    let alloc_map = BTreeMap::from([(chain.to_string(), targets)]);
+    let written = serde_json::to_string_pretty(&alloc_map)
+        .map_err(|error| error.to_string())
+        .and_then(|alloc_map| fs::write(path, alloc_map).map_err(|error| error.to_string()));
+    match written {
+        Ok(_) => println!("Alloc points written to {path}"),
+        Err(error) => eprintln!("Error while writing {path}: {error}"),
+    }
+}
+
+fn print_plan(
+    mut plan: AllocPlan,
+    names: &HashMap<String, String>,
+    sushi_price: f64,
+    constraints: &OptimizerConstraints,
+) {
+    let proposals = &mut plan.proposals;
+    proposals.sort_by(|a, b| {
+        let diff_a = a.proposed_alloc as i64 - a.current_alloc as i64;
+        let diff_b = b.proposed_alloc as i64 - b.current_alloc as i64;
+        match diff_b.cmp(&diff_a) {
+            Ordering::Equal => b.proposed_alloc.cmp(&a.proposed_alloc),
+            ordering => ordering,
+        }
+    });
+
+    let proposals_table: Vec<Vec<CellStruct>> = proposals
+        .iter()
+        .map(|proposal| {
+            vec![
+                names
+                    .get(&proposal.pair)
+                    .map_or("?", |name| name.as_str())
+                    .cell(),
+                proposal.pair.as_str().cell(),
+                proposal.pid.cell(),
+                proposal.current_alloc.cell(),
+                proposal.proposed_alloc.cell(),
+                format!(
+                    "{:+}",
+                    proposal.proposed_alloc as i64 - proposal.current_alloc as i64
+                )
+                .cell(),
+                proposal.current_sushi_per_day.round().cell(),
+                proposal.proposed_sushi_per_day.round().cell(),
+                format!("{} $", proposal.current_fees_per_day.round()).cell(),
+                format!("{} $", proposal.expected_fees_per_day.round()).cell(),
+                if proposal.protected { "yes" } else { "" }.cell(),
+            ]
+        })
+        .collect();
+    let proposals_table = proposals_table.table().title(vec![
+        "Pair".cell(),
+        "Address".cell(),
+        "Pid".cell(),
+        "Alloc".cell(),
+        "New alloc".cell(),
+        "Diff".cell(),
+        "SUSHI/day".cell(),
+        "New SUSHI/day".cell(),
+        "Fees/day".cell(),
+        "Exp. fees/day".cell(),
+        "Protected".cell(),
+    ]);
+
+    print_stdout(proposals_table).expect("Error while pinting result.");
+
+    let net = |sushi: f64, fees: f64| fees - sushi * sushi_price;
+    let current_sushi: f64 = proposals.iter().map(|p| p.current_sushi_per_day).sum();
+    let current_fees: f64 = proposals.iter().map(|p| p.current_fees_per_day).sum();
+    let proposed_fees: f64 = proposals.iter().map(|p| p.expected_fees_per_day).sum();
+    println!(
+        "New total alloc point: {}, emission: {} SUSHI/day (budget {}).",
+        plan.total_alloc_point,
+        plan.sushi_per_day.round(),
+        constraints.budget.round()
+    );
+    if let Some(sushi_per_second) = plan.sushi_per_second() {
+        println!("Emission changes, setSushiPerSecond({sushi_per_second}) is needed, see --out.");
+    }
+    println!(
+        "Net revenue per day: {} $ now, {} $ expected.",
+        net(current_sushi, current_fees).round(),
+        net(plan.sushi_per_day, proposed_fees).round()
+    );
+}
@@ -0,0 +1,101 @@
+use std::cmp::Ordering;
+
+use clap::{Arg, ArgMatches, Command};
+use cli_table::{print_stdout, Cell, CellStruct, Table};
+
+use crate::{
+    helpers::farm::{
+        pools_with_sushi::{query_multichain_pools_with_sushi, query_pools_with_sushi},
+        reconcile::{reconcile_funding, FundingStatus},
+    },
+    network::MINICHEF_SUBGRAPH,
+};
+
+pub fn command() -> Command {
+    let tolerance_arg = Arg::new("tolerance")
+        .help("Relative gap between mainnet funding and minichef emission before flagging a chain.")
+        .long("tolerance")
+        .default_value("0.05")
+        .value_parser(clap::value_parser!(f64));
+
+    Command::new("reconcile")
+        .about("Compare SUSHI minted by mainnet dummy pools with what each minichef emits.")
+        .arg(tolerance_arg)
+}
+
+fn status_label(status: FundingStatus) -> &'static str {
+    match status {
+        FundingStatus::Balanced => "ok",
+        FundingStatus::OverFunded => "over-funded",
+        FundingStatus::UnderFunded => "under-funded",
+        FundingStatus::NotFunded => "no dummy pool",
+        FundingStatus::NoMinichef => "no minichef data",
+        FundingStatus::NotEmitted => "not bridged",
+    }
+}
+
+pub fn execute(params: &ArgMatches) {
+    let tolerance = *params.get_one::<f64>("tolerance").unwrap(); //default to 0.05
+
+    let mainnet = match query_pools_with_sushi("ethereum".to_string()) {
+        Ok(mainnet) => mainnet,
+        Err(error) => {
+            eprintln!("Error while querying farms: {:#?}", error);
+            return;
+        }
+    };
+    let chains: Vec<String> = MINICHEF_SUBGRAPH
+        .keys()
+        .map(|chain| chain.to_string())
+        .collect();
+    let minichefs = query_multichain_pools_with_sushi(chains);
+
+    let mut fundings = reconcile_funding(&mainnet, &minichefs, tolerance);
+    //biggest gaps first
+    fundings.sort_by(|a, b| {
+        let gap_a = a.difference().unwrap_or_default().abs();
+        let gap_b = b.difference().unwrap_or_default().abs();
+        if gap_a > gap_b {
+            return Ordering::Less;
+        }
+        Ordering::Greater
+    });
+
+    let table: Vec<Vec<CellStruct>> = fundings
+        .iter()
+        .map(|funding| {
+            vec![
+                funding.chain.as_str().cell(),
+                funding
+                    .pids
+                    .iter()
+                    .map(|pid| pid.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ")
+                    .cell(),
+                funding.mainnet_sushi_per_day.round().cell(),
+                match funding.minichef_sushi_per_day {
+                    Some(sushi_per_day) => sushi_per_day.round().to_string(),
+                    None => "-".to_string(),
+                }
+                .cell(),
+                match funding.difference() {
+                    Some(difference) => format!("{:+}", difference.round()),
+                    None => "-".to_string(),
+                }
+                .cell(),
+                status_label(funding.status).cell(),
+            ]
+        })
+        .collect();
+    let table = table.table().title(vec![
+        "Chain".cell(),
+        "Dummy pids".cell(),
+        "Mainnet SUSHI/day".cell(),
+        "Minichef SUSHI/day".cell(),
+        "Difference".cell(),
+        "Status".cell(),
+    ]);
+
+    print_stdout(table).expect("Error while pinting result.");
+}
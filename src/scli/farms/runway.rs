@@ -0,0 +1,89 @@
+use std::cmp::Ordering;
+
+use clap::{Arg, ArgMatches, Command};
+use cli_table::{print_stdout, Cell, CellStruct, Table};
+
+use crate::{
+    helpers::farm::runway::{query_multichain_runway, query_runway, Runway},
+    network::MINICHEF_SUBGRAPH,
+};
+
+pub fn command() -> Command {
+    let network_arg = Arg::new("network")
+        .help("MiniChef network to check, all of them if not set. Ex: polygon.")
+        .short('n')
+        .long("network");
+    let target_days_arg = Arg::new("target-days")
+        .help("Days of emissions the top-up should cover.")
+        .short('t')
+        .long("target-days")
+        .default_value("30")
+        .value_parser(clap::value_parser!(u32));
+
+    Command::new("runway")
+        .about("Check how many days of emissions each MiniChef SUSHI balance still covers.")
+        .arg(network_arg)
+        .arg(target_days_arg)
+}
+
+pub fn execute(params: &ArgMatches) {
+    let target_days = *params.get_one::<u32>("target-days").unwrap() as f64; //default to 30
+
+    let mut runways: Vec<Runway> = match params.get_one::<String>("network") {
+        Some(chain) => match query_runway(chain.clone()) {
+            Ok(runway) => vec![runway],
+            Err(error) => {
+                eprintln!("Error while querying runway: {:#?}", error);
+                return;
+            }
+        },
+        None => {
+            let chains: Vec<String> = MINICHEF_SUBGRAPH
+                .keys()
+                .map(|chain| chain.to_string())
+                .collect();
+            query_multichain_runway(chains).into_values().collect()
+        }
+    };
+
+    //emptiest chefs first
+    runways.sort_by(|a, b| {
+        if a.days_left() < b.days_left() {
+            return Ordering::Less;
+        }
+        Ordering::Greater
+    });
+
+    let table: Vec<Vec<CellStruct>> = runways
+        .iter()
+        .map(|runway| {
+            vec![
+                runway.chain.as_str().cell(),
+                runway.balance.round().cell(),
+                runway.unclaimed.round().cell(),
+                runway.available().round().cell(),
+                runway.sushi_per_day.round().cell(),
+                format!("{:.1}", runway.days_left()).cell(),
+                runway.top_up(target_days).ceil().cell(),
+            ]
+        })
+        .collect();
+    let table = table.table().title(vec![
+        "Chain".cell(),
+        "Balance".cell(),
+        "Unclaimed".cell(),
+        "Available".cell(),
+        "SUSHI/day".cell(),
+        "Days left".cell(),
+        format!("Top-up ({target_days} days)").cell(),
+    ]);
+
+    print_stdout(table).expect("Error while pinting result.");
+
+    for runway in runways.iter().filter(|runway| runway.truncated) {
+        eprintln!(
+            "Warning: too many stakers on {}, unclaimed rewards are underestimated.",
+            runway.chain
+        );
+    }
+}
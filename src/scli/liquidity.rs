@@ -0,0 +1,240 @@
+use std::cmp::Ordering;
+
+use clap::{Arg, ArgMatches, Command};
+use cli_table::{print_stdout, Cell, CellStruct, Table};
+
+use crate::{
+    helpers::{
+        block::block_timestamp::BlockSource,
+        farm::liquidity_flow::{
+            query_liquidity_flow, query_liquidity_flow_multichain, LiquidityFlow, PairFlow,
+        },
+        time::format_timestamp,
+        volume::period_volume::Period,
+    },
+    network::{LEGACY_SUBGRAPH, MINICHEF_SUBGRAPH},
+};
+
+pub fn command() -> Command {
+    let network_arg = Arg::new("network")
+        .help("Network to look at, all chains with farms if not set. Ex: polygon.")
+        .short('n')
+        .long("network");
+    let days_arg = Arg::new("days")
+        .help("Days in the past to sum liquidity events on, allocs are compared with the start of the period.")
+        .short('d')
+        .long("days")
+        .default_value("7")
+        .value_parser(clap::value_parser!(u32).range(1..=30));
+    let max_events_arg = Arg::new("max-events")
+        .help("Max number of Mint and Burn events counted per chain, oldest first, the end of the period is cut past it.")
+        .long("max-events")
+        .default_value("20000")
+        .value_parser(clap::value_parser!(usize));
+    let sort_by_arg = Arg::new("sort-by")
+        .help("Rank pairs by liquidity added, removed, net flow or alloc change (largest moves either way for the last two).")
+        .long("sort-by")
+        .value_parser(["added", "removed", "net", "alloc"])
+        .default_value("net");
+    let limit_arg = Arg::new("limit")
+        .help("Max number of pairs to print.")
+        .long("limit")
+        .default_value("20")
+        .value_parser(clap::value_parser!(usize));
+
+    Command::new("liquidity")
+        .about("Get liquidity added and removed by Mint and Burn events and how it follows farm alloc changes.")
+        .arg(network_arg)
+        .arg(days_arg)
+        .arg(max_events_arg)
+        .arg(sort_by_arg)
+        .arg(limit_arg)
+}
+
+fn usd(amount: f64) -> String {
+    format!("{} $", amount.round())
+}
+
+fn print_chains(flows: &[LiquidityFlow]) {
+    let table: Vec<Vec<CellStruct>> = flows
+        .iter()
+        .map(|flow| {
+            let pairs = flow.pairs.values();
+            vec![
+                flow.chain.as_str().cell(),
+                flow.start_block.cell(),
+                usd(flow.added_usd()).cell(),
+                usd(flow.removed_usd()).cell(),
+                usd(flow.added_usd() - flow.removed_usd()).cell(),
+                pairs.clone().map(|pair| pair.mints).sum::<usize>().cell(),
+                pairs.map(|pair| pair.burns).sum::<usize>().cell(),
+                match flow.alloc_correlation() {
+                    Some((correlation, count)) => format!("{correlation:.2} ({count} farms)"),
+                    None => "-".to_string(),
+                }
+                .cell(),
+            ]
+        })
+        .collect();
+    let table = table.table().title(vec![
+        "Chain".cell(),
+        "Start block".cell(),
+        "Added".cell(),
+        "Removed".cell(),
+        "Net flow".cell(),
+        "Mints".cell(),
+        "Burns".cell(),
+        "Alloc change / net flow correlation".cell(),
+    ]);
+    print_stdout(table).expect("Error while pinting result.");
+
+    for flow in flows {
+        if let Some(truncated_at) = flow.truncated_at {
+            println!(
+                "{}: events truncated at {}, raise --max-events to cover the whole period.",
+                flow.chain,
+                format_timestamp(truncated_at)
+            );
+        }
+        if flow.unpriced > 0 {
+            println!(
+                "{}: {} events without USD amount left out.",
+                flow.chain, flow.unpriced
+            );
+        }
+    }
+}
+
+//flows of every chain summed by direction of the alloc change
+fn print_alloc_changes(flows: &[LiquidityFlow]) {
+    let mut groups: Vec<(&str, usize, f64, f64)> = vec![
+        ("Increased", 0, 0.0, 0.0),
+        ("Decreased", 0, 0.0, 0.0),
+        ("Unchanged", 0, 0.0, 0.0),
+        ("Unfarmed", 0, 0.0, 0.0),
+    ];
+    for pair in flows.iter().flat_map(|flow| flow.pairs.values()) {
+        let group = match pair.alloc_change() {
+            _ if !pair.is_farmed() => 3,
+            change if change > 0.0 => 0,
+            change if change < 0.0 => 1,
+            _ => 2,
+        };
+        groups[group].1 += 1;
+        groups[group].2 += pair.added_usd;
+        groups[group].3 += pair.removed_usd;
+    }
+
+    let table: Vec<Vec<CellStruct>> = groups
+        .iter()
+        .map(|(name, count, added, removed)| {
+            vec![
+                name.cell(),
+                count.cell(),
+                usd(*added).cell(),
+                usd(*removed).cell(),
+                usd(added - removed).cell(),
+            ]
+        })
+        .collect();
+    let table = table.table().title(vec![
+        "Alloc".cell(),
+        "Pairs".cell(),
+        "Added".cell(),
+        "Removed".cell(),
+        "Net flow".cell(),
+    ]);
+    println!("Flows by alloc change:");
+    print_stdout(table).expect("Error while pinting result.");
+}
+
+fn print_pairs(flows: &[LiquidityFlow], sort_by: &str, limit: usize) {
+    let mut pairs: Vec<(&str, &PairFlow)> = flows
+        .iter()
+        .flat_map(|flow| flow.pairs.values().map(|pair| (flow.chain.as_str(), pair)))
+        .collect();
+    let key = |pair: &PairFlow| match sort_by {
+        "added" => pair.added_usd,
+        "removed" => pair.removed_usd,
+        "alloc" => pair.alloc_change().abs(),
+        _ => pair.net_usd().abs(),
+    };
+    pairs.sort_by(|a, b| {
+        if key(a.1) > key(b.1) {
+            return Ordering::Less;
+        }
+        Ordering::Greater
+    });
+
+    let table: Vec<Vec<CellStruct>> = pairs
+        .iter()
+        .take(limit)
+        .map(|(chain, pair)| {
+            vec![
+                chain.cell(),
+                pair.name.as_str().cell(),
+                pair.id.as_str().cell(),
+                usd(pair.added_usd).cell(),
+                usd(pair.removed_usd).cell(),
+                usd(pair.net_usd()).cell(),
+                if pair.is_farmed() {
+                    format!(
+                        "{:.2} % -> {:.2} %",
+                        pair.alloc_start * 100.0,
+                        pair.alloc_end * 100.0
+                    )
+                } else {
+                    "-".to_string()
+                }
+                .cell(),
+            ]
+        })
+        .collect();
+    let table = table.table().title(vec![
+        "Chain".cell(),
+        "Pair".cell(),
+        "Address".cell(),
+        "Added".cell(),
+        "Removed".cell(),
+        "Net flow".cell(),
+        "Emission share".cell(),
+    ]);
+    println!("Pairs:");
+    print_stdout(table).expect("Error while pinting result.");
+}
+
+pub fn execute(params: &ArgMatches) {
+    let period = Period {
+        days: *params.get_one::<u32>("days").unwrap(), //default to 7
+        block_source: BlockSource::Subgraph,
+        token_list: Default::default(),
+    };
+    let max_events = *params.get_one::<usize>("max-events").unwrap(); //default to 20000
+
+    let flows: Vec<LiquidityFlow> = match params.get_one::<String>("network") {
+        Some(chain) => match query_liquidity_flow(chain.clone(), &period, max_events) {
+            Ok(flow) => vec![flow],
+            Err(error) => {
+                eprintln!("Error while querying liquidity flow: {:#?}", error);
+                return;
+            }
+        },
+        None => {
+            let mut chains: Vec<String> = MINICHEF_SUBGRAPH
+                .keys()
+                .filter(|chain| LEGACY_SUBGRAPH.contains_key(chain))
+                .map(|chain| chain.to_string())
+                .collect();
+            chains.push("ethereum".to_string());
+            query_liquidity_flow_multichain(chains, period, max_events)
+        }
+    };
+
+    print_chains(&flows);
+    print_alloc_changes(&flows);
+    print_pairs(
+        &flows,
+        params.get_one::<String>("sort-by").unwrap(), //default to net
+        *params.get_one::<usize>("limit").unwrap(),   //default to 20
+    );
+}
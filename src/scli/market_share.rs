@@ -0,0 +1,185 @@
+use std::cmp::Ordering;
+
+use clap::{Arg, ArgMatches, Command};
+use cli_table::{print_stdout, Cell, CellStruct, Table};
+
+use crate::{
+    helpers::{
+        block::block_timestamp::BlockSource,
+        volume::{
+            dex_adapter::{DexAdapter, SUSHISWAP},
+            market_share::{query_chain_market, query_market_multichain, ChainMarket},
+        },
+    },
+    scli::args::read_json_file,
+};
+
+pub fn command() -> Command {
+    let network_arg = Arg::new("network")
+        .help("Network to look at, all chains where Sushi and another dex have a subgraph if not set. Ex: polygon.")
+        .short('n')
+        .long("network");
+    let days_arg = Arg::new("days")
+        .help("Days in the past to compare volumes on.")
+        .short('d')
+        .long("days")
+        .default_value("7")
+        .value_parser(clap::value_parser!(u32).range(1..=30));
+    let block_source_arg = Arg::new("block-source")
+        .help("Where to resolve the period start block: subgraph (rpc fallback) or rpc (cross-checked with subgraph).")
+        .long("block-source")
+        .value_parser(["subgraph", "rpc"])
+        .default_value("subgraph");
+    let adapters_arg = Arg::new("adapters")
+        .help("JSON array of extra v2 fork adapters {name, schema: {factory, volume_usd, liquidity_usd, tx_count}, subgraphs: {chain: url}}, replacing the built-in one of the same name.")
+        .long("adapters");
+
+    Command::new("market-share")
+        .about("Get Sushi share of the volume of Uniswap v2 style dexes per chain. Shares come from factory totals, which count every pair and so ignore the token list used by revenues.")
+        .arg(network_arg)
+        .arg(days_arg)
+        .arg(block_source_arg)
+        .arg(adapters_arg)
+}
+
+fn share(part: f64, total: f64) -> String {
+    match total {
+        total if total > 0.0 => format!("{:.2} %", part / total * 100.0),
+        _ => "-".to_string(),
+    }
+}
+
+fn print_chains(markets: &[ChainMarket]) {
+    let (mut sushi_volume, mut total_volume) = (0.0, 0.0);
+    let mut chains_table: Vec<Vec<CellStruct>> = markets
+        .iter()
+        .map(|market| {
+            let (volume, liquidity) = market
+                .sushi()
+                .map_or((0.0, 0.0), |sushi| (sushi.volume_usd, sushi.liquidity_usd));
+            sushi_volume += volume;
+            total_volume += market.volume_usd();
+            vec![
+                market.chain.as_str().cell(),
+                format!("{} $", volume.round()).cell(),
+                format!("{} $", market.volume_usd().round()).cell(),
+                share(volume, market.volume_usd()).cell(),
+                share(liquidity, market.liquidity_usd()).cell(),
+                market.dexes.len().cell(),
+                market.start_block.cell(),
+                market.end_block.cell(),
+            ]
+        })
+        .collect();
+    chains_table.push(vec![
+        "total".cell(),
+        format!("{} $", sushi_volume.round()).cell(),
+        format!("{} $", total_volume.round()).cell(),
+        share(sushi_volume, total_volume).cell(),
+        "".cell(),
+        "".cell(),
+        "".cell(),
+        "".cell(),
+    ]);
+    let chains_table = chains_table.table().title(vec![
+        "Chain".cell(),
+        "Sushi volume".cell(),
+        "V2 style volume".cell(),
+        "Sushi volume share".cell(),
+        "Sushi TVL share".cell(),
+        "Dexes".cell(),
+        "Start block".cell(),
+        "End block".cell(),
+    ]);
+    print_stdout(chains_table).expect("Error while pinting result.");
+}
+
+fn print_dexes(markets: &[ChainMarket]) {
+    let dexes_table: Vec<Vec<CellStruct>> = markets
+        .iter()
+        .flat_map(|market| {
+            market.dexes.iter().map(move |dex| {
+                vec![
+                    market.chain.as_str().cell(),
+                    dex.dex.as_str().cell(),
+                    format!("{} $", dex.volume_usd.round()).cell(),
+                    share(dex.volume_usd, market.volume_usd()).cell(),
+                    format!("{} $", dex.liquidity_usd.round()).cell(),
+                    share(dex.liquidity_usd, market.liquidity_usd()).cell(),
+                    dex.tx_count.cell(),
+                ]
+            })
+        })
+        .collect();
+    let dexes_table = dexes_table.table().title(vec![
+        "Chain".cell(),
+        "Dex".cell(),
+        "Volume".cell(),
+        "Volume share".cell(),
+        "TVL".cell(),
+        "TVL share".cell(),
+        "Transactions".cell(),
+    ]);
+    println!("Dexes:");
+    print_stdout(dexes_table).expect("Error while pinting result.");
+}
+
+pub fn execute(params: &ArgMatches) {
+    let days = *params.get_one::<u32>("days").unwrap(); //default to 7
+    let block_source =
+        BlockSource::parse(params.get_one::<String>("block-source").unwrap()).unwrap(); //checked by clap
+    let mut adapters = DexAdapter::builtin();
+    if let Some(path) = params.get_one::<String>("adapters") {
+        let extra: Vec<DexAdapter> = match read_json_file(path) {
+            Ok(extra) => extra,
+            Err(error) => {
+                eprintln!("Error while reading dex adapters: {:#?}", error);
+                return;
+            }
+        };
+        adapters.retain(|adapter| extra.iter().all(|extra| extra.name != adapter.name));
+        adapters.extend(extra);
+    }
+
+    let mut markets: Vec<ChainMarket> = match params.get_one::<String>("network") {
+        Some(chain) => match query_chain_market(chain.clone(), &adapters, days, block_source) {
+            Ok(market) => vec![market],
+            Err(error) => {
+                eprintln!("Error while querying market share: {:#?}", error);
+                return;
+            }
+        },
+        None => {
+            let sushi_chains = match adapters.iter().find(|adapter| adapter.name == SUSHISWAP) {
+                Some(sushi) => sushi.subgraphs.keys().cloned().collect(),
+                None => vec![],
+            };
+            let chains: Vec<String> = sushi_chains
+                .into_iter()
+                .filter(|chain| {
+                    adapters.iter().any(|adapter| {
+                        adapter.name != SUSHISWAP && adapter.subgraphs.contains_key(chain)
+                    })
+                })
+                .collect();
+            query_market_multichain(chains, &adapters, days, block_source)
+        }
+    };
+    markets.sort_by(|a, b| {
+        if a.volume_usd() > b.volume_usd() {
+            return Ordering::Less;
+        }
+        Ordering::Greater
+    });
+    for market in markets.iter_mut() {
+        market.dexes.sort_by(|a, b| {
+            if a.volume_usd > b.volume_usd {
+                return Ordering::Less;
+            }
+            Ordering::Greater
+        });
+    }
+
+    print_chains(&markets);
+    print_dexes(&markets);
+}
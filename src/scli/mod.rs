@@ -1,15 +1,70 @@
 use clap::Command;
 
+mod args;
+mod block;
+mod farms;
+mod liquidity;
+mod market_share;
+mod pair;
+mod pairs;
 mod revenues;
+mod swaps;
+mod volume;
 
 pub fn scli() {
+    let block = block::command();
+    let farms = farms::command();
     let revenues = revenues::command();
-    let scli = Command::new("scli-rs").subcommand(revenues);
+    let token = revenues::tokens::command();
+    let pair = pair::command();
+    let pairs = pairs::command();
+    let volume = volume::command();
+    let swaps = swaps::command();
+    let liquidity = liquidity::command();
+    let market_share = market_share::command();
+    let scli = Command::new("scli-rs")
+        .subcommand(block)
+        .subcommand(farms)
+        .subcommand(revenues)
+        .subcommand(token)
+        .subcommand(pair)
+        .subcommand(pairs)
+        .subcommand(volume)
+        .subcommand(swaps)
+        .subcommand(liquidity)
+        .subcommand(market_share);
 
     match scli.get_matches().subcommand() {
+        Some(("block", params)) => {
+            block::execute(params);
+        }
+        Some(("farms", params)) => {
+            farms::execute(params);
+        }
         Some(("revenues", params)) => {
             revenues::execute(params);
         }
+        Some(("token", params)) => {
+            revenues::tokens::execute_token(params);
+        }
+        Some(("pair", params)) => {
+            pair::execute(params);
+        }
+        Some(("pairs", params)) => {
+            pairs::execute(params);
+        }
+        Some(("volume", params)) => {
+            volume::execute(params);
+        }
+        Some(("swaps", params)) => {
+            swaps::execute(params);
+        }
+        Some(("liquidity", params)) => {
+            liquidity::execute(params);
+        }
+        Some(("market-share", params)) => {
+            market_share::execute(params);
+        }
         _ => println!("none"),
     }
 }
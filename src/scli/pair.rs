@@ -0,0 +1,232 @@
+use clap::{Arg, ArgMatches, Command};
+use cli_table::{print_stdout, Cell, CellStruct, Table};
+
+use crate::helpers::{
+    block::block_timestamp::{now, query_block_timestamp, BlockSource},
+    farm::pools_with_sushi::{query_pools_with_sushi, Pool, PoolsWithSushiQueryError},
+    time::{format_date, format_timestamp},
+    volume::{
+        pair_detail::{find_pairs_by_symbols, query_lp_holders, query_pair_detail},
+        pairs_state::query_pairs_state,
+    },
+};
+use crate::scli::args::{fee_models_arg, parse_fee_models};
+
+pub fn command() -> Command {
+    let pair_arg = Arg::new("pair")
+        .help("Pair address or token symbols. Ex: 0x06da... or SUSHI/WETH")
+        .required(true);
+    let network_arg = Arg::new("network")
+        .help("Network of the pair. Ex: ethereum.")
+        .short('n')
+        .long("network")
+        .required(true);
+    let days_arg = Arg::new("days")
+        .help("Days of volume and fees to sum up and of daily history to print.")
+        .short('d')
+        .long("days")
+        .default_value("7")
+        .value_parser(clap::value_parser!(u32).range(1..=365));
+
+    Command::new("pair")
+        .about("Get state, period volume, farm and daily history of a pair.")
+        .arg(pair_arg)
+        .arg(network_arg)
+        .arg(days_arg)
+        .arg(fee_models_arg())
+}
+
+//address given as is, TOKEN0/TOKEN1 resolved to the most liquid matching pair
+fn resolve_pair(chain: &str, pair: &str) -> Option<String> {
+    let (symbol0, symbol1) = match pair.split_once('/') {
+        Some(symbols) => symbols,
+        None => return Some(pair.to_lowercase()),
+    };
+    let pairs = match find_pairs_by_symbols(chain, symbol0, symbol1) {
+        Ok(pairs) => pairs,
+        Err(error) => {
+            eprintln!("Error while looking up pair: {:#?}", error);
+            return None;
+        }
+    };
+    match pairs.first() {
+        Some((id, _, _)) => {
+            if pairs.len() > 1 {
+                println!(
+                    "{} pairs match {pair}, showing the most liquid one.",
+                    pairs.len()
+                );
+            }
+            Some(id.clone())
+        }
+        None => {
+            eprintln!("No {pair} pair found on {chain}.");
+            None
+        }
+    }
+}
+
+pub fn execute(params: &ArgMatches) {
+    let chain = params.get_one::<String>("network").unwrap(); //required by clap
+    let days = *params.get_one::<u32>("days").unwrap(); //default to 7
+    let fee_models = match parse_fee_models(params) {
+        Some(fee_models) => fee_models,
+        None => return,
+    };
+    let id = match resolve_pair(chain, params.get_one::<String>("pair").unwrap()) {
+        Some(id) => id,
+        None => return,
+    };
+
+    let pair = match query_pair_detail(chain, &id, days) {
+        Ok(pair) => pair,
+        Err(error) => {
+            eprintln!("Error while querying pair: {:#?}", error);
+            return;
+        }
+    };
+    let start_block = match query_block_timestamp(chain, days, BlockSource::Subgraph) {
+        Ok(block) => block as u64,
+        Err(error) => {
+            eprintln!("Error while querying block: {:#?}", error);
+            return;
+        }
+    };
+    let ids = vec![id.clone()];
+    let (start, end) = match (
+        query_pairs_state(chain, &ids, Some(start_block)),
+        query_pairs_state(chain, &ids, None),
+    ) {
+        (Ok(start), Ok(end)) => (start, end),
+        (Err(error), _) | (_, Err(error)) => {
+            eprintln!("Error while querying pair state: {:#?}", error);
+            return;
+        }
+    };
+    let period_pair = match end.get(&id) {
+        Some(end) => end.period_pair(start.get(&id)),
+        None => {
+            eprintln!("Error while querying pair state: {id} not found.");
+            return;
+        }
+    };
+    let treasury_share = fee_models
+        .model_for(chain, &period_pair, now())
+        .share_of(&period_pair);
+    //chains without chef have no farm
+    let farm: Option<Pool> = match query_pools_with_sushi(chain.clone()) {
+        Ok(minichef) => minichef.pools.get(&id).cloned(),
+        Err(PoolsWithSushiQueryError::UnknownChain(_)) => None,
+        Err(error) => {
+            eprintln!("Error while querying farms: {:#?}", error);
+            None
+        }
+    };
+    let holders = match query_lp_holders(chain, &id) {
+        Ok((holders, false)) => holders.to_string(),
+        Ok((holders, true)) => format!("{holders}+"),
+        Err(error) => {
+            eprintln!("Error while querying LP holders: {:#?}", error);
+            "?".to_string()
+        }
+    };
+
+    let mut detail: Vec<Vec<CellStruct>> = vec![
+        vec!["Pair".cell(), pair.name.as_str().cell()],
+        vec!["Address".cell(), pair.id.as_str().cell()],
+        vec!["Protocol".cell(), format!("{:?}", pair.protocol).cell()],
+        vec![
+            "Token0".cell(),
+            format!("{} ({})", pair.token0.symbol, pair.token0.id).cell(),
+        ],
+        vec![
+            "Token1".cell(),
+            format!("{} ({})", pair.token1.symbol, pair.token1.id).cell(),
+        ],
+        vec![
+            "Reserves".cell(),
+            format!(
+                "{} {} / {} {}",
+                pair.reserve0, pair.token0.symbol, pair.reserve1, pair.token1.symbol
+            )
+            .cell(),
+        ],
+        vec![
+            "Price".cell(),
+            format!(
+                "1 {} = {} {} / 1 {} = {} {}",
+                pair.token0.symbol,
+                pair.token1_price,
+                pair.token1.symbol,
+                pair.token1.symbol,
+                pair.token0_price,
+                pair.token0.symbol
+            )
+            .cell(),
+        ],
+        vec![
+            "Liquidity".cell(),
+            format!("{} $", pair.liquidity_usd.round()).cell(),
+        ],
+        vec!["LP supply".cell(), (pair.total_supply / 1e18).cell()],
+        vec!["LP holders".cell(), holders.cell()],
+        vec!["Swap fee".cell(), format!("{} bps", pair.swap_fee).cell()],
+        vec![
+            format!("Volume ({days}d)").cell(),
+            format!("{} $", period_pair.volume_usd.round()).cell(),
+        ],
+        vec![
+            format!("Fees ({days}d)").cell(),
+            format!("{} $", period_pair.fees_usd.round()).cell(),
+        ],
+        vec![
+            format!("Fees to Treasury ({days}d)").cell(),
+            format!("{} $", (period_pair.fees_usd * treasury_share).round()).cell(),
+        ],
+        vec![
+            "Volume (all time)".cell(),
+            format!("{} $", pair.volume_usd.round()).cell(),
+        ],
+        vec![
+            "Fees (all time)".cell(),
+            format!("{} $", pair.fees_usd.round()).cell(),
+        ],
+        vec!["Transactions".cell(), pair.tx_count.cell()],
+        vec!["Created".cell(), format_timestamp(pair.created_at).cell()],
+    ];
+    match farm {
+        Some(farm) => {
+            detail.push(vec!["Farm pid".cell(), farm.pid.cell()]);
+            detail.push(vec!["Alloc point".cell(), farm.alloc_point.cell()]);
+            detail.push(vec![
+                "SUSHI per day".cell(),
+                format!("{:.2}", farm.sushi_per_day).cell(),
+            ]);
+        }
+        None => detail.push(vec!["Farm".cell(), "none".cell()]),
+    }
+    print_stdout(detail.table()).expect("Error while pinting result.");
+
+    let history: Vec<Vec<CellStruct>> = pair
+        .days
+        .iter()
+        .map(|day| {
+            vec![
+                format_date(day.date).cell(),
+                format!("{} $", day.volume_usd.round()).cell(),
+                format!("{} $", day.fees_usd.round()).cell(),
+                format!("{} $", day.liquidity_usd.round()).cell(),
+                day.tx_count.cell(),
+            ]
+        })
+        .collect();
+    let history = history.table().title(vec![
+        "Date".cell(),
+        "Volume".cell(),
+        "Fees".cell(),
+        "Liquidity".cell(),
+        "Transactions".cell(),
+    ]);
+    println!("Daily history:");
+    print_stdout(history).expect("Error while pinting result.");
+}
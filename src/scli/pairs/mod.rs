@@ -0,0 +1,19 @@
+use clap::{ArgMatches, Command};
+
+mod top;
+
+pub fn command() -> Command {
+    Command::new("pairs")
+        .about("Compare pairs across chains.")
+        .subcommand_required(true)
+        .subcommand(top::command())
+}
+
+pub fn execute(params: &ArgMatches) {
+    match params.subcommand() {
+        Some(("top", params)) => {
+            top::execute(params);
+        }
+        _ => println!("none"),
+    }
+}
@@ -0,0 +1,166 @@
+use std::{cmp::Ordering, collections::HashMap};
+
+use clap::{Arg, ArgMatches, Command};
+use cli_table::{print_stdout, Cell, CellStruct, Table};
+
+use crate::{
+    helpers::{
+        block::block_timestamp::BlockSource, price::query_sushi_price,
+        volume::period_volume::Period,
+    },
+    scli::{
+        args::{fee_models_arg, parse_fee_models, parse_token_list_config, token_list_args},
+        revenues::{fetch_chains_data, pair_revenues, PairRevenues},
+        volume::{print_token_lists, print_unread_protocol_fees},
+    },
+};
+
+pub fn command() -> Command {
+    let network_arg = Arg::new("network")
+        .help("Only rank pairs of a network, all networks if not set. Ex: polygon.")
+        .short('n')
+        .long("network");
+    let days_arg = Arg::new("days")
+        .help("Days in the past to query.")
+        .short('d')
+        .long("days")
+        .default_value("7")
+        .value_parser(clap::value_parser!(u32).range(1..=30));
+    let sort_by_arg = Arg::new("sort-by")
+        .help("Rank pairs by volume, treasury fees, net revenue (fees - farm spend) or treasury fees over liquidity.")
+        .long("sort-by")
+        .value_parser(["volume", "fees", "revenue", "fee-tvl"])
+        .default_value("volume");
+    let min_liquidity_arg = Arg::new("min-liquidity")
+        .help("Min liquidity in USD of a pair to be ranked.")
+        .long("min-liquidity")
+        .default_value("0")
+        .value_parser(clap::value_parser!(f64));
+    let token_arg = Arg::new("token")
+        .help("Only rank pairs containing a token, by symbol or address. Ex: SUSHI")
+        .long("token");
+    let limit_arg = Arg::new("limit")
+        .help("Max number of pairs to print.")
+        .long("limit")
+        .default_value("20")
+        .value_parser(clap::value_parser!(usize));
+
+    Command::new("top")
+        .about("Rank pairs of every chain by volume, fees, net revenue or fees over liquidity.")
+        .arg(network_arg)
+        .arg(days_arg)
+        .arg(sort_by_arg)
+        .arg(min_liquidity_arg)
+        .arg(token_arg)
+        .arg(limit_arg)
+        .arg(fee_models_arg())
+        .args(token_list_args())
+}
+
+//treasury fees over the period per dollar of liquidity
+fn fee_tvl(pair: &PairRevenues) -> f64 {
+    match pair.liquidity {
+        liquidity if liquidity > 0.0 => pair.fees / liquidity,
+        _ => 0.0,
+    }
+}
+
+fn has_token(pair: &PairRevenues, token: &str) -> bool {
+    [&pair.token0, &pair.token1]
+        .iter()
+        .any(|pair_token| pair_token.id == token || pair_token.symbol.to_lowercase() == token)
+}
+
+pub fn execute(params: &ArgMatches) {
+    let days = *params.get_one::<u32>("days").unwrap(); //default to 7
+    let period = Period {
+        days,
+        block_source: BlockSource::Subgraph,
+        token_list: parse_token_list_config(params),
+    };
+    let sort_by = params.get_one::<String>("sort-by").unwrap(); //default to volume
+    let min_liquidity = *params.get_one::<f64>("min-liquidity").unwrap(); //default to 0
+    let token = params
+        .get_one::<String>("token")
+        .map(|token| token.to_lowercase());
+    let limit = *params.get_one::<usize>("limit").unwrap(); //default to 20
+    let fee_models = match parse_fee_models(params) {
+        Some(fee_models) => fee_models,
+        None => return,
+    };
+    let sushi_price = match query_sushi_price() {
+        Ok(price) => price,
+        Err(error) => {
+            eprintln!("Error while querying sushi price: {:#?}", error);
+            return;
+        }
+    };
+    let chains_data =
+        match fetch_chains_data(params.get_one::<String>("network"), period, &HashMap::new()) {
+            Some((chains_data, _)) => chains_data,
+            None => return,
+        };
+
+    let mut pairs: Vec<(String, PairRevenues)> = vec![];
+    for data in chains_data.iter() {
+        for pair in pair_revenues(
+            &data.chain,
+            data.days(),
+            &data.volume,
+            data.minichef.as_ref(),
+            sushi_price,
+            &fee_models,
+        ) {
+            if pair.liquidity < min_liquidity {
+                continue;
+            }
+            if let Some(token) = &token {
+                if !has_token(&pair, token) {
+                    continue;
+                }
+            }
+            pairs.push((data.chain.clone(), pair));
+        }
+    }
+
+    let key = |pair: &PairRevenues| match sort_by.as_str() {
+        "fees" => pair.fees,
+        "revenue" => pair.fees - pair.spent,
+        "fee-tvl" => fee_tvl(pair),
+        _ => pair.volume,
+    };
+    pairs.sort_by(|a, b| key(&b.1).partial_cmp(&key(&a.1)).unwrap_or(Ordering::Equal));
+
+    let table: Vec<Vec<CellStruct>> = pairs
+        .iter()
+        .take(limit)
+        .map(|(chain, pair)| {
+            vec![
+                chain.as_str().cell(),
+                pair.name.as_str().cell(),
+                pair.id.as_str().cell(),
+                format!("{} $", pair.volume.round()).cell(),
+                format!("{} $", pair.fees.round()).cell(),
+                format!("{} $", pair.spent.round()).cell(),
+                format!("{} $", (pair.fees - pair.spent).round()).cell(),
+                format!("{} $", pair.liquidity.round()).cell(),
+                format!("{:.3}%", fee_tvl(pair) * 100.0).cell(),
+            ]
+        })
+        .collect();
+    let table = table.table().title(vec![
+        "Chain".cell(),
+        "Pair".cell(),
+        "Address".cell(),
+        "Volume".cell(),
+        "Fees (to Treasury)".cell(),
+        "Spent on farm".cell(),
+        "Net revenue".cell(),
+        "Liquidity".cell(),
+        "Fees / TVL".cell(),
+    ]);
+
+    print_stdout(table).expect("Error while pinting result.");
+    print_token_lists(chains_data.iter().map(|data| (&data.chain, &data.volume)));
+    print_unread_protocol_fees(chains_data.iter().map(|data| (&data.chain, &data.volume)));
+}
@@ -0,0 +1,175 @@
+use std::cmp::Ordering;
+
+use clap::{Arg, ArgAction, ArgMatches};
+use cli_table::{print_stdout, Cell, CellStruct, Table};
+use serde::Serialize;
+
+use crate::helpers::{
+    fees::fee_model::FeeModels,
+    volume::anomaly::{detect_anomalies_multichain, AnomalyThresholds, PairAnomaly},
+};
+
+use super::ChainData;
+
+pub fn args() -> Vec<Arg> {
+    vec![
+        Arg::new("exclude-anomalies")
+            .help("Leave out of revenues the pairs flagged as anomalies and show what they weighed.")
+            .long("exclude-anomalies")
+            .action(ArgAction::SetTrue),
+        Arg::new("max-volume-liquidity")
+            .help("Daily volume over liquidity above which a pair is an anomaly.")
+            .long("max-volume-liquidity")
+            .default_value("50")
+            .value_parser(clap::value_parser!(f64)),
+        Arg::new("max-volume-jump")
+            .help("Daily volume over the daily volume of the 30 previous days above which a pair is an anomaly.")
+            .long("max-volume-jump")
+            .default_value("20")
+            .value_parser(clap::value_parser!(f64)),
+        Arg::new("max-trader-share")
+            .help("Share of a pair volume swapped to its 3 top addresses above which it is an anomaly.")
+            .long("max-trader-share")
+            .default_value("0.9")
+            .value_parser(clap::value_parser!(f64)),
+        Arg::new("anomaly-checks")
+            .help("Pairs per chain, by volume, checked against their history and swaps.")
+            .long("anomaly-checks")
+            .default_value("10")
+            .value_parser(clap::value_parser!(usize)),
+    ]
+}
+
+pub struct ExcludedAnomaly {
+    chain: String,
+    anomaly: PairAnomaly,
+    treasury_fees: f64,
+}
+
+/// Pair excluded from a chain revenues, as listed in the JSON output.
+#[derive(Debug, Serialize)]
+pub struct ExcludedPair {
+    id: String,
+    name: String,
+    volume: f64,
+    treasury_fees: f64,
+    flags: Vec<String>,
+}
+
+/// Detect anomalies on every chain and remove the flagged pairs from its volume.
+pub fn exclude(
+    params: &ArgMatches,
+    chains_data: &mut [ChainData],
+    fee_models: &FeeModels,
+) -> Vec<ExcludedAnomaly> {
+    let thresholds = AnomalyThresholds {
+        max_volume_liquidity: *params.get_one::<f64>("max-volume-liquidity").unwrap(), //default to 50
+        max_volume_jump: *params.get_one::<f64>("max-volume-jump").unwrap(), //default to 20
+        max_trader_share: *params.get_one::<f64>("max-trader-share").unwrap(), //default to 0.9
+        deep_checks: *params.get_one::<usize>("anomaly-checks").unwrap(),    //default to 10
+    };
+    let chains = chains_data
+        .iter()
+        .map(|data| {
+            let pairs = data.volume.pairs.values().cloned().collect();
+            let period = (data.start_timestamp, data.end_timestamp);
+            (data.chain.clone(), pairs, period)
+        })
+        .collect();
+    let mut anomalies = detect_anomalies_multichain(chains, thresholds);
+
+    let mut excluded: Vec<ExcludedAnomaly> = vec![];
+    for data in chains_data.iter_mut() {
+        let timestamp = data.end_timestamp;
+        for anomaly in anomalies.remove(&data.chain).unwrap_or_default() {
+            data.volume.pairs.remove(&anomaly.pair.id);
+            let treasury_fees = anomaly.pair.fees_usd
+                * fee_models
+                    .model_for(&data.chain, &anomaly.pair, timestamp)
+                    .share_of(&anomaly.pair);
+            excluded.push(ExcludedAnomaly {
+                chain: data.chain.clone(),
+                anomaly,
+                treasury_fees,
+            });
+        }
+    }
+    excluded
+}
+
+/// Pairs removed from the volume of a chain.
+pub fn chain_pairs(excluded: &[ExcludedAnomaly], chain: &str) -> Vec<ExcludedPair> {
+    excluded
+        .iter()
+        .filter(|excluded| excluded.chain == chain)
+        .map(|excluded| ExcludedPair {
+            id: excluded.anomaly.pair.id.clone(),
+            name: excluded.anomaly.pair.name.clone(),
+            volume: excluded.anomaly.pair.volume_usd,
+            treasury_fees: excluded.treasury_fees,
+            flags: excluded
+                .anomaly
+                .flags
+                .iter()
+                .map(|flag| flag.describe())
+                .collect(),
+        })
+        .collect()
+}
+
+/// True if the pair was removed from the chain volume as an anomaly.
+pub fn is_excluded(excluded: &[ExcludedAnomaly], chain: &str, id: &str) -> bool {
+    excluded
+        .iter()
+        .any(|excluded| excluded.chain == chain && excluded.anomaly.pair.id == id)
+}
+
+pub fn print(excluded: &mut [ExcludedAnomaly]) {
+    excluded.sort_by(|a, b| {
+        if a.anomaly.pair.volume_usd > b.anomaly.pair.volume_usd {
+            return Ordering::Less;
+        }
+        Ordering::Greater
+    });
+    let table: Vec<Vec<CellStruct>> = excluded
+        .iter()
+        .map(|excluded| {
+            let pair = &excluded.anomaly.pair;
+            let flags: Vec<String> = excluded
+                .anomaly
+                .flags
+                .iter()
+                .map(|flag| flag.describe())
+                .collect();
+            vec![
+                excluded.chain.as_str().cell(),
+                pair.name.as_str().cell(),
+                pair.id.as_str().cell(),
+                format!("{} $", pair.volume_usd.round()).cell(),
+                format!("{} $", excluded.treasury_fees.round()).cell(),
+                flags.join(", ").cell(),
+            ]
+        })
+        .collect();
+    let table = table.table().title(vec![
+        "Chain".cell(),
+        "Pair".cell(),
+        "Address".cell(),
+        "Volume".cell(),
+        "Fees (to Treasury)".cell(),
+        "Anomalies".cell(),
+    ]);
+    println!("Pairs excluded as anomalies:");
+    print_stdout(table).expect("Error while pinting result.");
+
+    let volume: f64 = excluded
+        .iter()
+        .map(|excluded| excluded.anomaly.pair.volume_usd)
+        .sum();
+    let fees: f64 = excluded.iter().map(|excluded| excluded.treasury_fees).sum();
+    println!(
+        "Excluded: {} $ of volume, {} $ of fees to treasury.",
+        volume.round(),
+        fees.round()
+    );
+}
@@ -0,0 +1,169 @@
+use std::{cmp::Ordering, collections::HashMap};
+
+use clap::{Arg, ArgAction};
+use cli_table::{print_stdout, Cell, CellStruct, Table};
+
+use crate::helpers::{
+    block::block_timestamp::now,
+    fees::fee_model::FeeModels,
+    token_list::TokenListConfig,
+    volume::period_volume::{query_period_volume_multichain, BlockBoundaries, Pair, Period},
+};
+
+use super::{
+    anomalies::{is_excluded, ExcludedAnomaly},
+    ChainData,
+};
+
+const LARGEST_EXCLUDED_PAIRS: usize = 15;
+
+pub fn args() -> Vec<Arg> {
+    vec![Arg::new("excluded")
+        .help("Re-query every chain without token list and report the volume and fees of the pairs it filtered out.")
+        .long("excluded")
+        .action(ArgAction::SetTrue)]
+}
+
+struct ChainExcluded {
+    chain: String,
+    counted_volume: f64,
+    volume: f64,
+    fees: f64,
+    treasury_fees: f64,
+    truncated: bool, //the unfiltered query hit its cap
+}
+
+/// Query the period of every chain again without token list, pinned to the same blocks,
+/// and report the pairs missing from the filtered data, anomalies aside.
+pub fn execute(
+    chains_data: &[ChainData],
+    period: &Period,
+    fee_models: &FeeModels,
+    anomalies: &[ExcludedAnomaly],
+) {
+    let boundaries: HashMap<String, BlockBoundaries> = chains_data
+        .iter()
+        .map(|data| {
+            let boundaries = BlockBoundaries {
+                start: Some(data.volume.start_block),
+                end: Some(data.volume.end_block),
+            };
+            (data.chain.clone(), boundaries)
+        })
+        .collect();
+    let unfiltered_period = Period {
+        token_list: TokenListConfig::all_pairs(),
+        ..period.clone()
+    };
+    let chains: Vec<String> = chains_data.iter().map(|data| data.chain.clone()).collect();
+    let unfiltered = query_period_volume_multichain(chains, unfiltered_period, &boundaries);
+
+    let mut chains_excluded: Vec<ChainExcluded> = vec![];
+    let mut excluded_pairs: Vec<(&String, &Pair)> = vec![];
+    for data in chains_data {
+        let volume = match unfiltered.get(&data.chain) {
+            Some(volume) => volume,
+            None => continue, //error already printed
+        };
+        let timestamp = volume.end_timestamp.unwrap_or_else(now);
+        let mut chain_excluded = ChainExcluded {
+            chain: data.chain.clone(),
+            counted_volume: data.volume.pairs.values().map(|pair| pair.volume_usd).sum(),
+            volume: 0.0,
+            fees: 0.0,
+            treasury_fees: 0.0,
+            truncated: volume.truncated,
+        };
+        for pair in volume.pairs.values() {
+            if data.volume.pairs.contains_key(&pair.id)
+                || is_excluded(anomalies, &data.chain, &pair.id)
+            {
+                continue;
+            }
+            chain_excluded.volume += pair.volume_usd;
+            chain_excluded.fees += pair.fees_usd;
+            chain_excluded.treasury_fees += pair.fees_usd
+                * fee_models
+                    .model_for(&data.chain, pair, timestamp)
+                    .share_of(pair);
+            excluded_pairs.push((&data.chain, pair));
+        }
+        chains_excluded.push(chain_excluded);
+    }
+
+    chains_excluded.sort_by(|a, b| {
+        if a.volume > b.volume {
+            return Ordering::Less;
+        }
+        Ordering::Greater
+    });
+    let chains_table: Vec<Vec<CellStruct>> = chains_excluded
+        .iter()
+        .map(|chain| {
+            let share = match chain.counted_volume + chain.volume {
+                total if total > 0.0 => chain.volume / total * 100.0,
+                _ => 0.0,
+            };
+            vec![
+                chain.chain.as_str().cell(),
+                format!("{} $", chain.counted_volume.round()).cell(),
+                format!("{} $", chain.volume.round()).cell(),
+                format!("{:.2} %", share).cell(),
+                format!("{} $", chain.fees.round()).cell(),
+                format!("{} $", chain.treasury_fees.round()).cell(),
+            ]
+        })
+        .collect();
+    let chains_table = chains_table.table().title(vec![
+        "Chain".cell(),
+        "Counted volume".cell(),
+        "Excluded volume".cell(),
+        "Excluded share".cell(),
+        "Excluded fees".cell(),
+        "Excluded fees (to Treasury)".cell(),
+    ]);
+    println!("Volume excluded by the token lists:");
+    print_stdout(chains_table).expect("Error while pinting result.");
+    let truncated: Vec<&str> = chains_excluded
+        .iter()
+        .filter(|chain| chain.truncated)
+        .map(|chain| chain.chain.as_str())
+        .collect();
+    if !truncated.is_empty() {
+        println!(
+            "Unfiltered query truncated on {}, pairs beyond its cap are missing so excluded totals are a lower bound.",
+            truncated.join(", ")
+        );
+    }
+
+    excluded_pairs.sort_by(|a, b| {
+        if a.1.volume_usd > b.1.volume_usd {
+            return Ordering::Less;
+        }
+        Ordering::Greater
+    });
+    let pairs_table: Vec<Vec<CellStruct>> = excluded_pairs
+        .iter()
+        .take(LARGEST_EXCLUDED_PAIRS)
+        .map(|(chain, pair)| {
+            vec![
+                chain.as_str().cell(),
+                pair.name.as_str().cell(),
+                pair.id.as_str().cell(),
+                format!("{} $", pair.volume_usd.round()).cell(),
+                format!("{} $", pair.fees_usd.round()).cell(),
+                format!("{} $", pair.liquidity_usd.round()).cell(),
+            ]
+        })
+        .collect();
+    let pairs_table = pairs_table.table().title(vec![
+        "Chain".cell(),
+        "Pair".cell(),
+        "Address".cell(),
+        "Volume".cell(),
+        "Fees".cell(),
+        "Liquidity".cell(),
+    ]);
+    println!("Largest excluded pairs:");
+    print_stdout(pairs_table).expect("Error while pinting result.");
+}
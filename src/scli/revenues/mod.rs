@@ -0,0 +1,789 @@
+use std::{cmp::Ordering, collections::HashMap, error::Error};
+
+use clap::{Arg, ArgAction, ArgMatches, Command};
+use cli_table::{print_stdout, Cell, CellStruct, Table};
+use serde::Serialize;
+
+use crate::{
+    helpers::{
+        block::{
+            block_rpc::query_block_by_number,
+            block_timestamp::{now, BlockSource},
+        },
+        farm::pools_with_sushi::{
+            query_multichain_pools_with_sushi_at, query_pools_with_sushi_at, Minichef,
+        },
+        fees::fee_model::{FeeModel, FeeModels},
+        price::query_sushi_price,
+        volume::{
+            period_volume::{
+                query_chain_period_volume, query_period_volume_multichain, BlockBoundaries, Pair,
+                Period, PeriodVolume, Protocol, Token,
+            },
+            snapshots::{
+                average_liquidity, query_factory_snapshots, query_factory_snapshots_multichain,
+                query_pairs_average_liquidity_multichain, Granularity, Snapshot,
+            },
+        },
+    },
+    network::{LEGACY_SUBGRAPH, MINICHEF_SUBGRAPH, V3_SUBGRAPH},
+    scli::{
+        args::{
+            fee_models_arg, parse_chain_value, parse_fee_models, parse_token_list_config,
+            read_json_file, token_list_args,
+        },
+        volume::{print_token_lists, print_unread_protocol_fees},
+    },
+};
+
+mod anomalies;
+mod excluded;
+mod simulate;
+pub(super) mod tokens;
+mod unattributed;
+
+pub fn command() -> Command {
+    let network_arg = Arg::new("network")
+        .help("Network to query. Ex: ethereum.")
+        .short('n')
+        .long("network");
+    let days_arg = Arg::new("days")
+        .help("Days in the past to query. Ex: 7 (for weekly revenue)")
+        .short('d')
+        .long("days")
+        .default_value("1");
+    let block_source_arg = Arg::new("block-source")
+        .help("Where to resolve the period start block: subgraph (rpc fallback) or rpc (cross-checked with subgraph).")
+        .long("block-source")
+        .value_parser(["subgraph", "rpc"])
+        .default_value("subgraph");
+    let start_block_arg = Arg::new("start-block")
+        .help("Pin the period start block of a chain, bypassing the timestamp lookup. Ex: ethereum=16700000")
+        .long("start-block")
+        .action(ArgAction::Append)
+        .value_parser(parse_chain_value::<u64>);
+    let end_block_arg = Arg::new("end-block")
+        .help("Pin the period end block of a chain instead of the subgraph head. Ex: ethereum=16750000")
+        .long("end-block")
+        .action(ArgAction::Append)
+        .value_parser(parse_chain_value::<u64>);
+    let blocks_file_arg = Arg::new("blocks-file")
+        .help("JSON file pinning period blocks per chain. Ex: {\"ethereum\": {\"start\": 16700000, \"end\": 16750000}}")
+        .long("blocks-file");
+    let pairs_arg = Arg::new("pairs")
+        .help("Print the 3 best and worst pairs of each chain with their TVL and yields.")
+        .long("pairs")
+        .action(ArgAction::SetTrue);
+    let json_arg = Arg::new("json")
+        .help("Print chains revenues, TVL, yields, best/worst pairs and excluded anomalies as JSON instead of tables.")
+        .long("json")
+        .action(ArgAction::SetTrue)
+        .conflicts_with_all(["group-by", "pairs", "unattributed", "excluded", "simulate"]);
+
+    Command::new("revenues")
+        .about("Get revenues for all chains by comparing fees with sushi spent.")
+        .arg(network_arg)
+        .arg(days_arg)
+        .arg(block_source_arg)
+        .arg(start_block_arg)
+        .arg(end_block_arg)
+        .arg(blocks_file_arg)
+        .arg(fee_models_arg())
+        .arg(pairs_arg)
+        .arg(json_arg)
+        .args(simulate::args())
+        .args(unattributed::args())
+        .args(excluded::args())
+        .args(anomalies::args())
+        .args(tokens::args())
+        .args(token_list_args())
+}
+
+fn parse_block_boundaries(
+    params: &ArgMatches,
+) -> Result<HashMap<String, BlockBoundaries>, Box<dyn Error>> {
+    let mut boundaries: HashMap<String, BlockBoundaries> =
+        match params.get_one::<String>("blocks-file") {
+            Some(path) => read_json_file(path)?,
+            None => HashMap::new(),
+        };
+    if let Some(start_blocks) = params.get_many::<(String, u64)>("start-block") {
+        for (chain, block) in start_blocks {
+            boundaries.entry(chain.clone()).or_default().start = Some(*block);
+        }
+    }
+    if let Some(end_blocks) = params.get_many::<(String, u64)>("end-block") {
+        for (chain, block) in end_blocks {
+            boundaries.entry(chain.clone()).or_default().end = Some(*block);
+        }
+    }
+    for (chain, boundary) in boundaries.iter() {
+        if let (Some(start), Some(end)) = (boundary.start, boundary.end) {
+            if start >= end {
+                return Err(
+                    format!("{chain} start block {start} is not before end block {end}").into(),
+                );
+            }
+        }
+    }
+    Ok(boundaries)
+}
+
+fn parse_days(days: &str) -> u32 {
+    let days = match days.parse::<u32>() {
+        Ok(days) => days,
+        Err(error) => {
+            eprintln!(
+                "Error while parsing days param, make sure to enter a valid number. {}",
+                error
+            );
+            panic!()
+        }
+    };
+    if days > 30 || days == 0 {
+        eprintln!("Please enter days between 1 and 30.");
+        panic!()
+    }
+    days
+}
+
+/// Period amounts per dollar of liquidity of the same pairs (period average when known),
+/// fees and emissions annualized, volume per day.
+#[derive(Debug, Clone, Copy, Serialize)]
+pub(super) struct TvlYields {
+    fees: f64,
+    emissions: f64,
+    volume: f64,
+}
+
+impl TvlYields {
+    fn new(fees: f64, spent: f64, volume: f64, liquidity: f64, days: f64) -> TvlYields {
+        if liquidity <= 0.0 {
+            return TvlYields {
+                fees: 0.0,
+                emissions: 0.0,
+                volume: 0.0,
+            };
+        }
+        let per_year = 365.0 / days;
+        TvlYields {
+            fees: fees * per_year / liquidity,
+            emissions: spent * per_year / liquidity,
+            volume: volume / days / liquidity,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[allow(dead_code)]
+pub(super) struct PairRevenues {
+    pub(super) id: String,
+    pub(super) name: String,
+    pub(super) token0: Token,
+    pub(super) token1: Token,
+    pub(super) volume: f64,
+    pub(super) fees: f64,
+    pub(super) spent: f64,
+    pub(super) liquidity: f64,
+    pub(super) average_liquidity: Option<f64>, //only fetched for best and worst pairs
+    pub(super) yields: Option<TvlYields>,
+    pub(super) fee_model: String,
+}
+
+impl PairRevenues {
+    pub fn new(
+        pair: &Pair,
+        fee_model: &FeeModel,
+        sushi_amount: f64,
+        sushi_price: f64,
+    ) -> PairRevenues {
+        PairRevenues {
+            id: pair.id.clone(),
+            name: pair.name.clone(),
+            token0: pair.token0.clone(),
+            token1: pair.token1.clone(),
+            volume: pair.volume_usd,
+            fees: pair.fees_usd * fee_model.share_of(pair),
+            spent: sushi_amount * sushi_price,
+            liquidity: pair.liquidity_usd,
+            average_liquidity: None,
+            yields: None,
+            fee_model: fee_model.name.clone(),
+        }
+    }
+}
+
+/// Everything fetched for a chain, kept around so revenues can be recomputed.
+pub(super) struct ChainData {
+    pub(super) chain: String,
+    pub(super) volume: PeriodVolume,
+    pub(super) minichef: Option<Minichef>,
+    pub(super) snapshots: Vec<Snapshot>, //factory day snapshots of the period, legacy and trident
+    pub(super) start_timestamp: u64,     //of the period start block
+    pub(super) end_timestamp: u64,       //of the period end block
+}
+
+impl ChainData {
+    /// Length of the period in days, from the timestamps of its start and end blocks.
+    pub(super) fn days(&self) -> f64 {
+        self.end_timestamp.saturating_sub(self.start_timestamp) as f64 / 86_400.0
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[allow(dead_code)]
+struct ChainRevenues {
+    chain: String,
+    start_block: u64,
+    end_block: u64,
+    start_timestamp: u64,
+    end_timestamp: u64,
+    total_volume: f64,
+    total_fees: f64,
+    total_spent: f64,
+    liquidity: f64,
+    average_liquidity: f64,
+    counted_liquidity: f64, //of the pairs counted in the totals, at the end of the period
+    yields: TvlYields,      //of the pairs counted
+    fee_models: Vec<String>,
+    token_list: String,
+    token_list_fallback: bool, //remote list unreachable, snapshot or no filter used instead
+    unread_protocol_fees: Vec<String>, //V3 pools whose treasury fees come from the fee models
+    best: Vec<PairRevenues>,
+    worst: Vec<PairRevenues>,
+    excluded_anomalies: Vec<anomalies::ExcludedPair>,
+}
+
+pub(super) fn pair_revenues(
+    chain: &str,
+    days: f64,
+    volume: &PeriodVolume,
+    minichef: Option<&Minichef>,
+    sushi_price: f64,
+    fee_models: &FeeModels,
+) -> Vec<PairRevenues> {
+    let timestamp = volume.end_timestamp.unwrap_or_else(now);
+    volume
+        .pairs
+        .values()
+        .map(|pair| {
+            let fee_model = fee_models.model_for(chain, pair, timestamp);
+            let mut sushi_amount = 0.0;
+            if let Some(pool) = minichef.and_then(|minichef| minichef.pools.get(&pair.id)) {
+                sushi_amount = pool.sushi_per_day * days;
+            }
+            PairRevenues::new(pair, fee_model, sushi_amount, sushi_price)
+        })
+        .collect()
+}
+
+//current and period average TVL of a chain, v3 pools only have a current TVL
+fn chain_liquidity(volume: &PeriodVolume, snapshots: &[Snapshot]) -> (f64, f64) {
+    let pairs_liquidity = |v3: bool| -> f64 {
+        volume
+            .pairs
+            .values()
+            .filter(|pair| (pair.protocol == Protocol::V3) == v3)
+            .map(|pair| pair.liquidity_usd)
+            .sum()
+    };
+    let v3_liquidity = pairs_liquidity(true);
+    match (snapshots.last(), average_liquidity(snapshots)) {
+        (Some(last), Some(average)) => (last.liquidity_usd + v3_liquidity, average + v3_liquidity),
+        _ => {
+            //no factory snapshots, fall back on the pairs in the token list
+            let liquidity = pairs_liquidity(false) + v3_liquidity;
+            (liquidity, liquidity)
+        }
+    }
+}
+
+fn compute_revenues(
+    data: &ChainData,
+    minichef: Option<&Minichef>,
+    sushi_price: f64,
+    fee_models: &FeeModels,
+) -> ChainRevenues {
+    let chain = data.chain.clone();
+    let days = data.days();
+    let volume = &data.volume;
+    let mut total_volume = 0.0;
+    let mut total_fees = 0.0;
+    let mut total_spent = 0.0;
+    let mut applied_models: Vec<String> = vec![];
+
+    if let Some(minichef) = minichef {
+        total_spent = minichef.sushi_per_day * sushi_price * days;
+    }
+    let mut pair_revenues = pair_revenues(&chain, days, volume, minichef, sushi_price, fee_models);
+    for revenues in pair_revenues.iter() {
+        if !applied_models.contains(&revenues.fee_model) {
+            applied_models.push(revenues.fee_model.clone());
+        }
+        total_volume += revenues.volume;
+        total_fees += revenues.fees; //only the fee model share goes to treasury
+    }
+
+    pair_revenues.sort_by(|a, b| {
+        let earned_a = a.fees - a.spent;
+        let earned_b = b.fees - b.spent;
+
+        if earned_a > earned_b {
+            return Ordering::Less;
+        };
+        Ordering::Greater
+    });
+
+    let (liquidity, average_liquidity) = chain_liquidity(volume, &data.snapshots);
+    //factory TVL covers pairs outside the token list too, yields only use the counted ones
+    let counted_liquidity: f64 = volume.pairs.values().map(|pair| pair.liquidity_usd).sum();
+    ChainRevenues {
+        chain,
+        start_block: volume.start_block,
+        end_block: volume.end_block,
+        start_timestamp: data.start_timestamp,
+        end_timestamp: data.end_timestamp,
+        total_volume,
+        total_fees,
+        total_spent,
+        liquidity,
+        average_liquidity,
+        counted_liquidity,
+        yields: TvlYields::new(
+            total_fees,
+            total_spent,
+            total_volume,
+            counted_liquidity,
+            days,
+        ),
+        fee_models: applied_models,
+        token_list: volume.token_list.describe(),
+        token_list_fallback: volume.token_list.fallback,
+        unread_protocol_fees: volume.unread_protocol_fees.clone(),
+        best: if pair_revenues.len() > 3 {
+            pair_revenues[0..3].to_vec()
+        } else {
+            [].into()
+        },
+        worst: if pair_revenues.len() > 3 {
+            pair_revenues[(pair_revenues.len() - 3)..pair_revenues.len()].to_vec()
+        } else {
+            [].into()
+        },
+        excluded_anomalies: vec![],
+    }
+}
+
+//timestamps of the period start and end blocks, start counted back from the end if unreadable
+fn period_timestamps(chain: &str, volume: &PeriodVolume, days: u32) -> (u64, u64) {
+    let end = match volume.end_timestamp {
+        Some(end) => end,
+        None => match query_block_by_number(chain, volume.end_block) {
+            Ok(block) => block.timestamp,
+            Err(_) => now(),
+        },
+    };
+    let start = match query_block_by_number(chain, volume.start_block) {
+        Ok(block) => block.timestamp,
+        Err(error) => {
+            eprintln!(
+                "Error while querying {} start block timestamp: {:#?}",
+                chain, error
+            );
+            end.saturating_sub(86_400 * days as u64)
+        }
+    };
+    (start, end)
+}
+
+//factory day snapshots overlapping a period
+fn period_snapshots(snapshots: Vec<Snapshot>, (start, end): (u64, u64)) -> Vec<Snapshot> {
+    snapshots
+        .into_iter()
+        .filter(|snapshot| snapshot.date + 86_400 > start && snapshot.date <= end)
+        .collect()
+}
+
+/// Period volume, farms and factory snapshots of one chain, or of every chain with a subgraph.
+/// Minichefs of chains without any volume are returned apart.
+pub(super) fn fetch_chains_data(
+    network: Option<&String>,
+    period: Period,
+    boundaries: &HashMap<String, BlockBoundaries>,
+) -> Option<(Vec<ChainData>, HashMap<String, Minichef>)> {
+    //chefs are read at a period end given by the user, at their own head otherwise as their
+    //subgraph can lag behind the exchange one
+    let pinned_end_block = |chain: &str| boundaries.get(chain).and_then(|blocks| blocks.end);
+    let mut chains_data: Vec<ChainData> = vec![];
+    if let Some(chain) = network {
+        let volume = match query_chain_period_volume(
+            chain.clone(),
+            &period,
+            boundaries.get(chain).copied().unwrap_or_default(),
+        ) {
+            Ok(volume) => volume,
+            Err(error) => {
+                eprintln!("Error while querying volume: {:#?}", error);
+                return None;
+            }
+        };
+
+        let (start_timestamp, end_timestamp) = period_timestamps(chain, &volume, period.days);
+        let mut minichef = None;
+        if chain.contains("ethereum") || MINICHEF_SUBGRAPH.contains_key(chain) {
+            minichef = match query_pools_with_sushi_at(chain.clone(), pinned_end_block(chain)) {
+                Ok(minichef) => Some(minichef),
+                Err(error) => {
+                    eprintln!("Error while querying farms: {:#?}", error);
+                    return None;
+                }
+            };
+        }
+
+        let mut snapshots = vec![];
+        if LEGACY_SUBGRAPH.contains_key(chain) {
+            snapshots = match query_factory_snapshots(chain, start_timestamp, Granularity::Day) {
+                Ok(snapshots) => period_snapshots(snapshots, (start_timestamp, end_timestamp)),
+                Err(error) => {
+                    eprintln!("Error while querying snapshots: {:#?}", error);
+                    vec![]
+                }
+            };
+        }
+
+        chains_data.push(ChainData {
+            chain: chain.clone(),
+            volume,
+            minichef,
+            snapshots,
+            start_timestamp,
+            end_timestamp,
+        });
+    } else {
+        let mut chains: Vec<String> = LEGACY_SUBGRAPH
+            .keys()
+            .map(|chain| chain.to_string())
+            .collect();
+        for chain in V3_SUBGRAPH.keys() {
+            if !LEGACY_SUBGRAPH.contains_key(chain) {
+                chains.push(chain.to_string());
+            }
+        }
+        let days = period.days;
+        let volumes = query_period_volume_multichain(chains, period, boundaries);
+
+        let timestamps: HashMap<String, (u64, u64)> = volumes
+            .iter()
+            .map(|(chain, volume)| (chain.clone(), period_timestamps(chain, volume, days)))
+            .collect();
+
+        let minichef_chains: Vec<(String, Option<u64>)> = MINICHEF_SUBGRAPH
+            .keys()
+            .map(|chain| chain.to_string())
+            .chain(["ethereum".to_string()])
+            .map(|chain| {
+                let block = pinned_end_block(&chain);
+                (chain, block)
+            })
+            .collect();
+        let mut minichefs = query_multichain_pools_with_sushi_at(minichef_chains);
+
+        let legacy_chains: Vec<String> = volumes
+            .keys()
+            .filter(|chain| LEGACY_SUBGRAPH.contains_key(chain.as_str()))
+            .cloned()
+            .collect();
+        let from = timestamps
+            .values()
+            .map(|(start, _)| *start)
+            .min()
+            .unwrap_or_else(now);
+        let mut snapshots =
+            query_factory_snapshots_multichain(legacy_chains, from, Granularity::Day);
+
+        for (chain, volume) in volumes {
+            let minichef = minichefs.remove(&chain);
+            let (start_timestamp, end_timestamp) = timestamps[&chain];
+            let snapshots = match snapshots.remove(&chain) {
+                Some(snapshots) => period_snapshots(snapshots, (start_timestamp, end_timestamp)),
+                None => vec![],
+            };
+            chains_data.push(ChainData {
+                chain,
+                volume,
+                minichef,
+                snapshots,
+                start_timestamp,
+                end_timestamp,
+            });
+        }
+        return Some((chains_data, minichefs)); //chefs left have no volume
+    }
+    Some((chains_data, HashMap::new()))
+}
+
+pub fn execute(params: &ArgMatches) {
+    if let Err(error) = simulate::check_args(params) {
+        eprintln!("Error while reading simulation: {error}");
+        return;
+    }
+    let sushi_price = match query_sushi_price() {
+        Ok(price) => price,
+        Err(error) => {
+            eprintln!("Error while querying sushi price: {:#?}", error);
+            return;
+        }
+    };
+    let network = params.get_one::<String>("network");
+    let days = parse_days(params.get_one::<String>("days").unwrap()); //default to 1
+    let block_source =
+        BlockSource::parse(params.get_one::<String>("block-source").unwrap()).unwrap(); //checked by clap
+    let period = Period {
+        days,
+        block_source,
+        token_list: parse_token_list_config(params),
+    };
+    let boundaries = match parse_block_boundaries(params) {
+        Ok(boundaries) => boundaries,
+        Err(error) => {
+            eprintln!("Error while reading period blocks: {}", error);
+            return;
+        }
+    };
+    let fee_models = match parse_fee_models(params) {
+        Some(fee_models) => fee_models,
+        None => return,
+    };
+
+    let (mut chains_data, idle_minichefs) =
+        match fetch_chains_data(network, period.clone(), &boundaries) {
+            Some(chains_data) => chains_data,
+            None => return,
+        };
+    let mut excluded_anomalies = match params.get_flag("exclude-anomalies") {
+        true => Some(anomalies::exclude(params, &mut chains_data, &fee_models)),
+        false => None,
+    };
+
+    let mut revenues: Vec<ChainRevenues> = chains_data
+        .iter()
+        .map(|data| compute_revenues(data, data.minichef.as_ref(), sushi_price, &fee_models))
+        .collect();
+    if let Some(excluded_anomalies) = &excluded_anomalies {
+        for revenue in revenues.iter_mut() {
+            revenue.excluded_anomalies = anomalies::chain_pairs(excluded_anomalies, &revenue.chain);
+        }
+    }
+
+    revenues.sort_by(|a, b| {
+        if a.total_fees > b.total_fees {
+            return Ordering::Less;
+        }
+        Ordering::Greater
+    });
+
+    let json = params.get_flag("json");
+    if json || params.get_flag("pairs") {
+        attach_pairs_liquidity(&mut revenues);
+    }
+    if json {
+        match serde_json::to_string_pretty(&revenues) {
+            Ok(revenues) => println!("{revenues}"),
+            Err(error) => eprintln!("Error while serializing revenues: {:#?}", error),
+        }
+        return;
+    }
+
+    match params.get_one::<String>("group-by").unwrap().as_str() {
+        "token" => tokens::execute(&chains_data, sushi_price, &fee_models),
+        _ => print_revenues(&revenues), //default to chain
+    }
+    if params.get_flag("pairs") {
+        print_pairs(&revenues);
+    }
+    print_token_lists(chains_data.iter().map(|data| (&data.chain, &data.volume)));
+    print_unread_protocol_fees(chains_data.iter().map(|data| (&data.chain, &data.volume)));
+    if let Some(excluded_anomalies) = excluded_anomalies.as_mut() {
+        anomalies::print(excluded_anomalies);
+    }
+
+    if params.get_flag("unattributed") {
+        unattributed::execute(&chains_data, &idle_minichefs, days, sushi_price);
+    }
+    if params.get_flag("excluded") {
+        excluded::execute(
+            &chains_data,
+            &period,
+            &fee_models,
+            excluded_anomalies.as_deref().unwrap_or_default(),
+        );
+    }
+    if params.get_flag("simulate") {
+        simulate::execute(params, &chains_data, &revenues, sushi_price, &fee_models);
+    }
+}
+
+/// Average liquidity and yields of the best and worst pairs of each chain.
+fn attach_pairs_liquidity(revenues: &mut [ChainRevenues]) {
+    let requests: Vec<(String, Vec<String>, (u64, u64))> = revenues
+        .iter()
+        .filter(|revenue| LEGACY_SUBGRAPH.contains_key(revenue.chain.as_str()))
+        .map(|revenue| {
+            let ids = revenue
+                .best
+                .iter()
+                .chain(revenue.worst.iter())
+                .map(|pair| pair.id.clone())
+                .collect();
+            (
+                revenue.chain.clone(),
+                ids,
+                (revenue.start_timestamp, revenue.end_timestamp),
+            )
+        })
+        .collect();
+    let mut liquidities = query_pairs_average_liquidity_multichain(requests);
+
+    for revenue in revenues.iter_mut() {
+        let liquidities = liquidities.remove(&revenue.chain).unwrap_or_default();
+        let days = revenue
+            .end_timestamp
+            .saturating_sub(revenue.start_timestamp) as f64
+            / 86_400.0;
+        for pair in revenue.best.iter_mut().chain(revenue.worst.iter_mut()) {
+            pair.average_liquidity = liquidities.get(&pair.id).copied();
+            let liquidity = pair.average_liquidity.unwrap_or(pair.liquidity);
+            pair.yields = Some(TvlYields::new(
+                pair.fees,
+                pair.spent,
+                pair.volume,
+                liquidity,
+                days,
+            ));
+        }
+    }
+}
+
+fn yield_cells(yields: &TvlYields) -> Vec<CellStruct> {
+    vec![
+        format!("{:.2} %", yields.fees * 100.0).cell(),
+        format!("{:.2} %", yields.emissions * 100.0).cell(),
+        format!("{:.2} %", yields.volume * 100.0).cell(),
+    ]
+}
+
+fn print_pairs(revenues: &[ChainRevenues]) {
+    let pairs_table: Vec<Vec<CellStruct>> = revenues
+        .iter()
+        .flat_map(|revenue| {
+            revenue
+                .best
+                .iter()
+                .chain(revenue.worst.iter())
+                .map(|pair| (revenue.chain.as_str(), pair))
+        })
+        .map(|(chain, pair)| {
+            let mut row = vec![
+                chain.cell(),
+                pair.name.as_str().cell(),
+                pair.id.as_str().cell(),
+                format!("{} $", (pair.fees - pair.spent).round()).cell(),
+                format!("{} $", pair.liquidity.round()).cell(),
+                match pair.average_liquidity {
+                    Some(liquidity) => format!("{} $", liquidity.round()),
+                    None => "-".to_string(),
+                }
+                .cell(),
+            ];
+            if let Some(yields) = &pair.yields {
+                row.extend(yield_cells(yields));
+            }
+            row
+        })
+        .collect();
+    let pairs_table = pairs_table.table().title(vec![
+        "Chain".cell(),
+        "Pair".cell(),
+        "Address".cell(),
+        "Revenue".cell(),
+        "TVL".cell(),
+        "Avg TVL".cell(),
+        "Fees / TVL (yearly)".cell(),
+        "Emissions / TVL (yearly)".cell(),
+        "Volume / TVL (daily)".cell(),
+    ]);
+
+    println!("Best and worst pairs:");
+    print_stdout(pairs_table).expect("Error while pinting result.");
+}
+
+fn print_revenues(revenues: &[ChainRevenues]) {
+    let revenues_table: Vec<Vec<CellStruct>> = revenues
+        .iter()
+        .map(|revenue| {
+            vec![
+                revenue.chain.as_str().cell(),
+                format!("{} $", revenue.total_volume.round()).cell(),
+                format!("{} $", revenue.total_fees.round()).cell(),
+                format!("{} $", revenue.total_spent.round()).cell(),
+                format!("{} $", (revenue.total_fees - revenue.total_spent).round()).cell(),
+                revenue.fee_models.join(", ").cell(),
+            ]
+        })
+        .collect();
+    let revenues_table = revenues_table.table().title(vec![
+        "Chain".cell(),
+        "Volume".cell(),
+        "Fees (to Treasury)".cell(),
+        "Spent".cell(),
+        "Revenue".cell(),
+        "Fee model".cell(),
+    ]);
+
+    print_stdout(revenues_table).expect("Error while pinting result.");
+
+    let liquidity_table: Vec<Vec<CellStruct>> = revenues
+        .iter()
+        .map(|revenue| {
+            let mut row = vec![
+                revenue.chain.as_str().cell(),
+                format!("{} $", revenue.liquidity.round()).cell(),
+                format!("{} $", revenue.average_liquidity.round()).cell(),
+                format!("{} $", revenue.counted_liquidity.round()).cell(),
+            ];
+            row.extend(yield_cells(&revenue.yields));
+            row
+        })
+        .collect();
+    let liquidity_table = liquidity_table.table().title(vec![
+        "Chain".cell(),
+        "TVL".cell(),
+        "Avg TVL".cell(),
+        "Counted TVL".cell(),
+        "Fees / counted TVL (yearly)".cell(),
+        "Emissions / counted TVL (yearly)".cell(),
+        "Volume / counted TVL (daily)".cell(),
+    ]);
+
+    println!("Liquidity, counted being the pairs in the totals:");
+    print_stdout(liquidity_table).expect("Error while pinting result.");
+
+    let blocks_table: Vec<Vec<CellStruct>> = revenues
+        .iter()
+        .map(|revenue| {
+            vec![
+                revenue.chain.as_str().cell(),
+                revenue.start_block.cell(),
+                revenue.end_block.cell(),
+            ]
+        })
+        .collect();
+    let blocks_table = blocks_table.table().title(vec![
+        "Chain".cell(),
+        "Start block".cell(),
+        "End block".cell(),
+    ]);
+
+    println!("Period blocks used:");
+    print_stdout(blocks_table).expect("Error while pinting result.");
+}
@@ -0,0 +1,189 @@
+use std::collections::HashMap;
+
+use clap::{Arg, ArgAction, ArgMatches};
+use cli_table::{print_stdout, Cell, CellStruct, Table};
+
+use crate::{
+    helpers::{
+        farm::pools_with_sushi::{query_minichef_pools_by_pairs, Minichef},
+        fees::fee_model::FeeModels,
+    },
+    scli::args::{parse_chain_key_value, parse_chain_value},
+};
+
+use super::{compute_revenues, ChainData, ChainRevenues};
+
+pub fn args() -> Vec<Arg> {
+    vec![
+        Arg::new("simulate")
+            .help("Re-run revenues with the --sim-* overrides and print baseline vs simulated.")
+            .long("simulate")
+            .action(ArgAction::SetTrue),
+        Arg::new("sim-fee-share")
+            .help("Simulated share of the swap fees going to the treasury, for every pair. Ex: 0.25")
+            .long("sim-fee-share")
+            .requires("simulate")
+            .value_parser(clap::value_parser!(f64)),
+        Arg::new("sim-sushi-per-day")
+            .help("Simulated daily SUSHI emission of a MiniChef chain. Ex: polygon=5000")
+            .long("sim-sushi-per-day")
+            .requires("simulate")
+            .action(ArgAction::Append)
+            .value_parser(parse_chain_value::<f64>),
+        Arg::new("sim-alloc")
+            .help("Simulated alloc point of a MiniChef farm, by chain and pair address, idle farms included. Ex: polygon:0xc4e5...=100")
+            .long("sim-alloc")
+            .requires("simulate")
+            .action(ArgAction::Append)
+            .value_parser(parse_chain_key_value::<u32>),
+        Arg::new("sim-sushi-price")
+            .help("Simulated SUSHI price in USD. Ex: 1.2")
+            .long("sim-sushi-price")
+            .requires("simulate")
+            .value_parser(clap::value_parser!(f64)),
+    ]
+}
+
+/// Mainnet alloc points are shared between MasterChef v1, v2 and the dummy pools, only
+/// MiniChef chains can be simulated.
+pub fn check_args(params: &ArgMatches) -> Result<(), String> {
+    let sushi_per_day_chains = params
+        .get_many::<(String, f64)>("sim-sushi-per-day")
+        .into_iter()
+        .flatten()
+        .map(|(chain, _)| chain);
+    let alloc_chains = params
+        .get_many::<(String, String, u32)>("sim-alloc")
+        .into_iter()
+        .flatten()
+        .map(|(chain, _, _)| chain);
+    match sushi_per_day_chains
+        .chain(alloc_chains)
+        .find(|chain| chain.contains("ethereum"))
+    {
+        Some(_) => Err("mainnet emissions can't be simulated, use a MiniChef chain".to_string()),
+        None => Ok(()),
+    }
+}
+
+//farms without alloc points aren't part of the chef, fetch the overridden ones
+fn with_overridden_pools(
+    chain: &str,
+    minichef: &Minichef,
+    alloc_points: &HashMap<String, u32>,
+) -> Minichef {
+    let mut minichef = minichef.clone();
+    let missing: Vec<String> = alloc_points
+        .keys()
+        .filter(|pair| !minichef.pools.contains_key(*pair))
+        .cloned()
+        .collect();
+    if missing.is_empty() {
+        return minichef;
+    }
+    match query_minichef_pools_by_pairs(chain, &minichef, missing) {
+        Ok(pools) => {
+            for pool in pools {
+                minichef.pools.entry(pool.id.clone()).or_insert(pool);
+            }
+        }
+        Err(error) => eprintln!("Error while querying overridden farms: {:#?}", error),
+    }
+    minichef
+}
+
+pub fn execute(
+    params: &ArgMatches,
+    chains_data: &[ChainData],
+    baseline: &[ChainRevenues],
+    sushi_price: f64,
+    fee_models: &FeeModels,
+) {
+    let fee_models = match params.get_one::<f64>("sim-fee-share") {
+        Some(share) => FeeModels::flat(*share),
+        None => fee_models.clone(),
+    };
+    let sushi_price = *params
+        .get_one::<f64>("sim-sushi-price")
+        .unwrap_or(&sushi_price);
+    let sushi_per_day: HashMap<String, f64> =
+        match params.get_many::<(String, f64)>("sim-sushi-per-day") {
+            Some(values) => values.cloned().collect(),
+            None => HashMap::new(),
+        };
+    let mut alloc_points: HashMap<String, HashMap<String, u32>> = HashMap::new();
+    if let Some(values) = params.get_many::<(String, String, u32)>("sim-alloc") {
+        for (chain, pair, alloc_point) in values {
+            alloc_points
+                .entry(chain.clone())
+                .or_default()
+                .insert(pair.clone(), *alloc_point);
+        }
+    }
+
+    let no_alloc_points = HashMap::new();
+    let simulated: Vec<ChainRevenues> = chains_data
+        .iter()
+        .map(|data| {
+            let chain_alloc_points = alloc_points.get(&data.chain).unwrap_or(&no_alloc_points);
+            let minichef = data.minichef.as_ref().map(|minichef| {
+                with_overridden_pools(&data.chain, minichef, chain_alloc_points)
+                    .with_overrides(sushi_per_day.get(&data.chain).copied(), chain_alloc_points)
+            });
+            compute_revenues(data, minichef.as_ref(), sushi_price, &fee_models)
+        })
+        .collect();
+
+    print_simulation(baseline, &simulated);
+}
+
+fn print_simulation(baseline: &[ChainRevenues], simulated: &[ChainRevenues]) {
+    let mut total_baseline = 0.0;
+    let mut total_simulated = 0.0;
+    let mut simulation_table: Vec<Vec<CellStruct>> = vec![];
+    for revenue in baseline {
+        let simulation = match simulated.iter().find(|sim| sim.chain == revenue.chain) {
+            Some(simulation) => simulation,
+            None => continue,
+        };
+        let baseline_revenue = revenue.total_fees - revenue.total_spent;
+        let simulated_revenue = simulation.total_fees - simulation.total_spent;
+        total_baseline += baseline_revenue;
+        total_simulated += simulated_revenue;
+
+        simulation_table.push(vec![
+            revenue.chain.as_str().cell(),
+            format!("{} $", revenue.total_fees.round()).cell(),
+            format!("{} $", simulation.total_fees.round()).cell(),
+            format!("{} $", revenue.total_spent.round()).cell(),
+            format!("{} $", simulation.total_spent.round()).cell(),
+            format!("{} $", baseline_revenue.round()).cell(),
+            format!("{} $", simulated_revenue.round()).cell(),
+            format!("{} $", (simulated_revenue - baseline_revenue).round()).cell(),
+        ]);
+    }
+    simulation_table.push(vec![
+        "total".cell(),
+        "".cell(),
+        "".cell(),
+        "".cell(),
+        "".cell(),
+        format!("{} $", total_baseline.round()).cell(),
+        format!("{} $", total_simulated.round()).cell(),
+        format!("{} $", (total_simulated - total_baseline).round()).cell(),
+    ]);
+
+    let simulation_table = simulation_table.table().title(vec![
+        "Chain".cell(),
+        "Fees".cell(),
+        "Sim. fees".cell(),
+        "Spent".cell(),
+        "Sim. spent".cell(),
+        "Revenue".cell(),
+        "Sim. revenue".cell(),
+        "Delta".cell(),
+    ]);
+
+    println!("Simulation:");
+    print_stdout(simulation_table).expect("Error while pinting result.");
+}
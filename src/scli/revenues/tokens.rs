@@ -0,0 +1,254 @@
+use std::{cmp::Ordering, collections::HashMap};
+
+use clap::{Arg, ArgMatches, Command};
+use cli_table::{print_stdout, Cell, CellStruct, Table};
+
+use crate::helpers::{
+    block::block_timestamp::BlockSource, fees::fee_model::FeeModels, price::query_sushi_price,
+    volume::period_volume::Period,
+};
+
+use crate::scli::{
+    args::{fee_models_arg, parse_fee_models, parse_token_list_config, token_list_args},
+    volume::{print_token_lists, print_unread_protocol_fees},
+};
+
+use super::{fetch_chains_data, pair_revenues, ChainData, PairRevenues};
+
+pub fn args() -> Vec<Arg> {
+    vec![Arg::new("group-by")
+        .help("Group revenues by chain or by token, pair volume, fees and spend being split half/half across its two tokens.")
+        .long("group-by")
+        .value_parser(["chain", "token"])
+        .default_value("chain")]
+}
+
+/// Revenues of the pairs containing a token. Volume, fees and spend are split half/half
+/// between the two tokens of a pair so token rows add up to the chain totals.
+#[derive(Debug, Default)]
+struct TokenRevenues {
+    symbol: String,
+    chains: Vec<String>,
+    pairs: Vec<(String, PairRevenues)>,
+    volume: f64,
+    fees: f64,
+    spent: f64,
+}
+
+impl TokenRevenues {
+    fn add(&mut self, chain: &str, pair: &PairRevenues) {
+        if !self.chains.iter().any(|token_chain| token_chain == chain) {
+            self.chains.push(chain.to_string());
+        }
+        self.volume += pair.volume / 2.0;
+        self.fees += pair.fees / 2.0;
+        self.spent += pair.spent / 2.0;
+        self.pairs.push((chain.to_string(), pair.clone()));
+    }
+
+    /// Whole spend of the farms of pairs containing the token, unlike the halved `spent`.
+    fn farms_spent(&self) -> f64 {
+        self.pairs.iter().map(|(_, pair)| pair.spent).sum()
+    }
+}
+
+/// Token revenues keyed by symbol, or by chain and address if by_address is set.
+fn token_revenues(
+    chains_data: &[ChainData],
+    sushi_price: f64,
+    fee_models: &FeeModels,
+    by_address: bool,
+) -> HashMap<String, TokenRevenues> {
+    let mut tokens: HashMap<String, TokenRevenues> = HashMap::new();
+    for data in chains_data {
+        let pairs = pair_revenues(
+            &data.chain,
+            data.days(),
+            &data.volume,
+            data.minichef.as_ref(),
+            sushi_price,
+            fee_models,
+        );
+        for pair in pairs.iter() {
+            for token in [&pair.token0, &pair.token1] {
+                let key = match by_address {
+                    true => format!("{}:{}", data.chain, token.id),
+                    false => token.symbol.clone(),
+                };
+                let token_revenues = tokens.entry(key).or_default();
+                token_revenues.symbol = token.symbol.clone();
+                token_revenues.add(&data.chain, pair);
+            }
+        }
+    }
+    tokens
+}
+
+pub fn execute(chains_data: &[ChainData], sushi_price: f64, fee_models: &FeeModels) {
+    let mut tokens: Vec<TokenRevenues> =
+        token_revenues(chains_data, sushi_price, fee_models, false)
+            .into_values()
+            .collect();
+    tokens.sort_by(|a, b| {
+        if a.fees > b.fees {
+            return Ordering::Less;
+        }
+        Ordering::Greater
+    });
+
+    let table: Vec<Vec<CellStruct>> = tokens
+        .iter()
+        .map(|token| {
+            vec![
+                token.symbol.as_str().cell(),
+                token.chains.join(", ").cell(),
+                token.pairs.len().cell(),
+                format!("{} $", token.volume.round()).cell(),
+                format!("{} $", token.fees.round()).cell(),
+                format!("{} $", token.spent.round()).cell(),
+            ]
+        })
+        .collect();
+    let table = table.table().title(vec![
+        "Token".cell(),
+        "Chains".cell(),
+        "Pairs".cell(),
+        "Volume".cell(),
+        "Fees (to Treasury)".cell(),
+        "Spent on farms".cell(),
+    ]);
+
+    print_stdout(table).expect("Error while pinting result.");
+}
+
+pub fn command() -> Command {
+    let token_arg = Arg::new("token")
+        .help("Token symbol or address. Ex: SUSHI")
+        .required(true);
+    let network_arg = Arg::new("network")
+        .help("Network to query, all networks if not set. Ex: ethereum.")
+        .short('n')
+        .long("network");
+    let days_arg = Arg::new("days")
+        .help("Days in the past to query.")
+        .short('d')
+        .long("days")
+        .default_value("7")
+        .value_parser(clap::value_parser!(u32).range(1..=30));
+
+    Command::new("token")
+        .about("Get volume, fees and farm spend of the pairs containing a token.")
+        .arg(token_arg)
+        .arg(network_arg)
+        .arg(days_arg)
+        .arg(fee_models_arg())
+        .args(token_list_args())
+}
+
+pub fn execute_token(params: &ArgMatches) {
+    let token = params.get_one::<String>("token").unwrap().to_lowercase(); //required by clap
+    let days = *params.get_one::<u32>("days").unwrap(); //default to 7
+    let period = Period {
+        days,
+        block_source: BlockSource::Subgraph,
+        token_list: parse_token_list_config(params),
+    };
+    let fee_models = match parse_fee_models(params) {
+        Some(fee_models) => fee_models,
+        None => return,
+    };
+    let sushi_price = match query_sushi_price() {
+        Ok(price) => price,
+        Err(error) => {
+            eprintln!("Error while querying sushi price: {:#?}", error);
+            return;
+        }
+    };
+    let chains_data =
+        match fetch_chains_data(params.get_one::<String>("network"), period, &HashMap::new()) {
+            Some((chains_data, _)) => chains_data,
+            None => return,
+        };
+
+    let mut tokens: Vec<(String, TokenRevenues)> =
+        token_revenues(&chains_data, sushi_price, &fee_models, true)
+            .into_iter()
+            .filter(|(key, revenues)| {
+                revenues.symbol.to_lowercase() == token || key.ends_with(&format!(":{token}"))
+            })
+            .collect();
+    if tokens.is_empty() {
+        eprintln!("No pair with volume found for token {token}.");
+        return;
+    }
+    tokens.sort_by(|a, b| {
+        if a.1.fees > b.1.fees {
+            return Ordering::Less;
+        }
+        Ordering::Greater
+    });
+
+    let tokens_table: Vec<Vec<CellStruct>> = tokens
+        .iter()
+        .map(|(key, revenues)| {
+            let (chain, address) = key.split_once(':').unwrap_or_default();
+            vec![
+                chain.cell(),
+                revenues.symbol.as_str().cell(),
+                address.cell(),
+                revenues.pairs.len().cell(),
+                format!("{} $", revenues.volume.round()).cell(),
+                format!("{} $", revenues.fees.round()).cell(),
+                format!("{} $", revenues.farms_spent().round()).cell(),
+            ]
+        })
+        .collect();
+    let tokens_table = tokens_table.table().title(vec![
+        "Chain".cell(),
+        "Token".cell(),
+        "Address".cell(),
+        "Pairs".cell(),
+        "Volume (half of pairs)".cell(),
+        "Fees to Treasury (half of pairs)".cell(),
+        "Spent on its farms".cell(),
+    ]);
+    print_stdout(tokens_table).expect("Error while pinting result.");
+
+    let mut pairs: Vec<&(String, PairRevenues)> = tokens
+        .iter()
+        .flat_map(|(_, revenues)| revenues.pairs.iter())
+        .collect();
+    pairs.sort_by(|a, b| {
+        if a.1.fees > b.1.fees {
+            return Ordering::Less;
+        }
+        Ordering::Greater
+    });
+    let pairs_table: Vec<Vec<CellStruct>> = pairs
+        .iter()
+        .map(|(chain, pair)| {
+            vec![
+                chain.as_str().cell(),
+                pair.name.as_str().cell(),
+                pair.id.as_str().cell(),
+                format!("{} $", pair.volume.round()).cell(),
+                format!("{} $", pair.fees.round()).cell(),
+                format!("{} $", pair.spent.round()).cell(),
+                format!("{} $", (pair.fees - pair.spent).round()).cell(),
+            ]
+        })
+        .collect();
+    let pairs_table = pairs_table.table().title(vec![
+        "Chain".cell(),
+        "Pair".cell(),
+        "Address".cell(),
+        "Volume".cell(),
+        "Fees (to Treasury)".cell(),
+        "Spent".cell(),
+        "Revenue".cell(),
+    ]);
+    println!("Pairs:");
+    print_stdout(pairs_table).expect("Error while pinting result.");
+    print_token_lists(chains_data.iter().map(|data| (&data.chain, &data.volume)));
+    print_unread_protocol_fees(chains_data.iter().map(|data| (&data.chain, &data.volume)));
+}
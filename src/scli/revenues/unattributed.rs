@@ -0,0 +1,114 @@
+use std::{
+    cmp::Ordering,
+    collections::{HashMap, HashSet},
+};
+
+use clap::{Arg, ArgAction};
+use cli_table::{print_stdout, Cell, CellStruct, Table};
+
+use crate::helpers::farm::pools_with_sushi::Minichef;
+
+use super::ChainData;
+
+pub fn args() -> Vec<Arg> {
+    vec![Arg::new("unattributed")
+        .help("List farms whose spend couldn't be matched to a pair of the volume data.")
+        .long("unattributed")
+        .action(ArgAction::SetTrue)]
+}
+
+/// Farm counted in a chain spend but missing from its pairs breakdown, either a Trident pool,
+/// a pair outside the token list or beyond the query cap, or on a chain without any volume.
+struct UnattributedFarm {
+    chain: String,
+    pid: u32,
+    pair: String,
+    sushi_per_day: f64,
+    spent: f64, //over the chain period
+    chain_share: f64,
+}
+
+pub fn execute(
+    chains_data: &[ChainData],
+    idle_minichefs: &HashMap<String, Minichef>,
+    days: u32,
+    sushi_price: f64,
+) {
+    //chains without volume have no pair to match and span --days
+    let chains = chains_data
+        .iter()
+        .filter_map(|data| {
+            let minichef = data.minichef.as_ref()?;
+            let pairs: HashSet<&String> = data.volume.pairs.keys().collect();
+            Some((&data.chain, minichef, pairs, data.days()))
+        })
+        .chain(
+            idle_minichefs
+                .iter()
+                .map(|(chain, minichef)| (chain, minichef, HashSet::new(), days as f64)),
+        );
+
+    let mut farms: Vec<UnattributedFarm> = vec![];
+    let mut total_sushi_per_day = 0.0;
+    for (chain, minichef, pairs, days) in chains {
+        total_sushi_per_day += minichef.sushi_per_day;
+        for pool in minichef.pools.values() {
+            if pool.sushi_per_day == 0.0 || pairs.contains(&pool.id) {
+                continue;
+            }
+            farms.push(UnattributedFarm {
+                chain: chain.clone(),
+                pid: pool.pid,
+                pair: pool.id.clone(),
+                sushi_per_day: pool.sushi_per_day,
+                spent: pool.sushi_per_day * sushi_price * days,
+                chain_share: pool.sushi_per_day / minichef.sushi_per_day,
+            });
+        }
+    }
+
+    farms.sort_by(|a, b| {
+        if a.sushi_per_day > b.sushi_per_day {
+            return Ordering::Less;
+        }
+        Ordering::Greater
+    });
+
+    let table: Vec<Vec<CellStruct>> = farms
+        .iter()
+        .map(|farm| {
+            vec![
+                farm.chain.as_str().cell(),
+                farm.pid.cell(),
+                farm.pair.as_str().cell(),
+                farm.sushi_per_day.round().cell(),
+                format!("{} $", farm.spent.round()).cell(),
+                format!("{:.2} %", farm.chain_share * 100.0).cell(),
+            ]
+        })
+        .collect();
+    let table = table.table().title(vec![
+        "Chain".cell(),
+        "Pid".cell(),
+        "LP token".cell(),
+        "SUSHI/day".cell(),
+        "Spent".cell(),
+        "Share of chain spend".cell(),
+    ]);
+
+    println!("Farms without matching volume data:");
+    print_stdout(table).expect("Error while pinting result.");
+
+    let unattributed_sushi_per_day: f64 = farms.iter().map(|farm| farm.sushi_per_day).sum();
+    let unattributed_spent: f64 = farms.iter().map(|farm| farm.spent).sum();
+    let share = match total_sushi_per_day {
+        total if total > 0.0 => unattributed_sushi_per_day / total * 100.0,
+        _ => 0.0,
+    };
+    println!(
+        "Unattributed spend: {} $ ({} SUSHI/day), {:.2} % of total spend.",
+        unattributed_spent.round(),
+        unattributed_sushi_per_day.round(),
+        share
+    );
+}
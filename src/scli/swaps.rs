@@ -0,0 +1,254 @@
+use std::{cmp::Ordering, collections::HashMap};
+
+use clap::{Arg, ArgMatches, Command};
+use cli_table::{print_stdout, Cell, CellStruct, Table};
+
+use crate::helpers::{
+    block::block_timestamp::now,
+    time::format_timestamp,
+    volume::swaps::{query_swaps, Swap},
+};
+
+//upper bound in USD of each swap size bucket
+const SIZE_BUCKETS: [f64; 6] = [
+    100.0,
+    1_000.0,
+    10_000.0,
+    100_000.0,
+    1_000_000.0,
+    f64::INFINITY,
+];
+
+pub fn command() -> Command {
+    let network_arg = Arg::new("network")
+        .help("Network to query. Ex: ethereum.")
+        .short('n')
+        .long("network")
+        .required(true);
+    let hours_arg = Arg::new("hours")
+        .help("Hours in the past to fetch swaps from.")
+        .long("hours")
+        .default_value("24")
+        .value_parser(clap::value_parser!(u64).range(1..=720));
+    let pair_arg = Arg::new("pair")
+        .help("Comma separated pair addresses to restrict swaps to, every pair if not set.")
+        .long("pair");
+    let max_swaps_arg = Arg::new("max-swaps")
+        .help("Max number of swaps to fetch, oldest first, the end of the window is cut past it.")
+        .long("max-swaps")
+        .default_value("20000")
+        .value_parser(clap::value_parser!(usize));
+    let limit_arg = Arg::new("limit")
+        .help("Number of addresses and trades listed in the top tables.")
+        .long("limit")
+        .default_value("10")
+        .value_parser(clap::value_parser!(usize));
+
+    Command::new("swaps")
+        .about("Get traders, swap sizes, top addresses and largest trades of a chain.")
+        .arg(network_arg)
+        .arg(hours_arg)
+        .arg(pair_arg)
+        .arg(max_swaps_arg)
+        .arg(limit_arg)
+}
+
+fn sort_desc<T>(rows: &mut [T], key: impl Fn(&T) -> f64) {
+    rows.sort_by(|a, b| {
+        if key(a) > key(b) {
+            return Ordering::Less;
+        }
+        Ordering::Greater
+    });
+}
+
+fn share(part: f64, total: f64) -> String {
+    match total {
+        total if total > 0.0 => format!("{:.2} %", part / total * 100.0),
+        _ => "0 %".to_string(),
+    }
+}
+
+fn print_summary(swaps: &[Swap], volume: f64) {
+    let count = |address: fn(&Swap) -> &str| {
+        let mut addresses: Vec<&str> = swaps.iter().map(address).collect();
+        addresses.sort_unstable();
+        addresses.dedup();
+        addresses.len()
+    };
+    let mut amounts: Vec<f64> = swaps.iter().map(|swap| swap.amount_usd).collect();
+    amounts.sort_by(|a, b| a.total_cmp(b));
+    let median = amounts.get(amounts.len() / 2).copied().unwrap_or_default();
+    let average = match swaps.len() {
+        0 => 0.0,
+        len => volume / len as f64,
+    };
+
+    let table = vec![
+        vec!["Swaps".cell(), swaps.len().cell()],
+        vec![
+            "Transactions".cell(),
+            count(|swap| &swap.transaction).cell(),
+        ],
+        vec!["Volume".cell(), format!("{} $", volume.round()).cell()],
+        vec!["Distinct recipients".cell(), count(|swap| &swap.to).cell()],
+        vec!["Distinct senders".cell(), count(|swap| &swap.sender).cell()],
+        vec![
+            "Average swap".cell(),
+            format!("{} $", average.round()).cell(),
+        ],
+        vec!["Median swap".cell(), format!("{} $", median.round()).cell()],
+    ];
+    print_stdout(table.table()).expect("Error while pinting result.");
+}
+
+fn print_sizes(swaps: &[Swap], volume: f64) {
+    let mut buckets: Vec<(usize, f64)> = vec![(0, 0.0); SIZE_BUCKETS.len()];
+    for swap in swaps {
+        let bucket = SIZE_BUCKETS
+            .iter()
+            .position(|bound| swap.amount_usd < *bound)
+            .unwrap_or(SIZE_BUCKETS.len() - 1);
+        buckets[bucket].0 += 1;
+        buckets[bucket].1 += swap.amount_usd;
+    }
+
+    let mut lower = 0.0;
+    let table: Vec<Vec<CellStruct>> = buckets
+        .iter()
+        .zip(SIZE_BUCKETS)
+        .map(|((count, bucket_volume), upper)| {
+            let size = match upper {
+                upper if upper.is_infinite() => format!("> {lower} $"),
+                upper => format!("{lower} - {upper} $"),
+            };
+            lower = upper;
+            vec![
+                size.cell(),
+                count.cell(),
+                share(*count as f64, swaps.len() as f64).cell(),
+                format!("{} $", bucket_volume.round()).cell(),
+                share(*bucket_volume, volume).cell(),
+            ]
+        })
+        .collect();
+    let table = table.table().title(vec![
+        "Swap size".cell(),
+        "Swaps".cell(),
+        "Share of swaps".cell(),
+        "Volume".cell(),
+        "Share of volume".cell(),
+    ]);
+    println!("Swap sizes:");
+    print_stdout(table).expect("Error while pinting result.");
+}
+
+fn print_addresses(
+    title: &str,
+    swaps: &[Swap],
+    volume: f64,
+    limit: usize,
+    address: fn(&Swap) -> &str,
+) {
+    let mut addresses: HashMap<&str, (usize, f64)> = HashMap::new();
+    for swap in swaps {
+        let entry = addresses.entry(address(swap)).or_default();
+        entry.0 += 1;
+        entry.1 += swap.amount_usd;
+    }
+    let mut addresses: Vec<(&str, (usize, f64))> = addresses.into_iter().collect();
+    sort_desc(&mut addresses, |address| address.1 .1);
+
+    let table: Vec<Vec<CellStruct>> = addresses
+        .iter()
+        .take(limit)
+        .map(|(address, (count, address_volume))| {
+            vec![
+                address.cell(),
+                count.cell(),
+                format!("{} $", address_volume.round()).cell(),
+                share(*address_volume, volume).cell(),
+            ]
+        })
+        .collect();
+    let table = table.table().title(vec![
+        "Address".cell(),
+        "Swaps".cell(),
+        "Volume".cell(),
+        "Share of volume".cell(),
+    ]);
+    println!("{title}:");
+    print_stdout(table).expect("Error while pinting result.");
+}
+
+fn print_largest(swaps: &[Swap], limit: usize) {
+    let mut largest: Vec<&Swap> = swaps.iter().collect();
+    sort_desc(&mut largest, |swap| swap.amount_usd);
+
+    let table: Vec<Vec<CellStruct>> = largest
+        .iter()
+        .take(limit)
+        .map(|swap| {
+            vec![
+                format_timestamp(swap.timestamp).cell(),
+                swap.pair_name.as_str().cell(),
+                format!("{} $", swap.amount_usd.round()).cell(),
+                swap.sender.as_str().cell(),
+                swap.to.as_str().cell(),
+                swap.transaction.as_str().cell(),
+            ]
+        })
+        .collect();
+    let table = table.table().title(vec![
+        "Time".cell(),
+        "Pair".cell(),
+        "Amount".cell(),
+        "Sender".cell(),
+        "Recipient".cell(),
+        "Transaction".cell(),
+    ]);
+    println!("Largest trades:");
+    print_stdout(table).expect("Error while pinting result.");
+}
+
+pub fn execute(params: &ArgMatches) {
+    let chain = params.get_one::<String>("network").unwrap(); //required by clap
+    let hours = *params.get_one::<u64>("hours").unwrap(); //default to 24
+    let pairs: Option<Vec<String>> = params.get_one::<String>("pair").map(|pairs| {
+        pairs
+            .split(',')
+            .map(|pair| pair.trim().to_lowercase())
+            .collect()
+    });
+    let max_swaps = *params.get_one::<usize>("max-swaps").unwrap(); //default to 20000
+    let limit = *params.get_one::<usize>("limit").unwrap(); //default to 10
+
+    let from = now().saturating_sub(hours * 3_600);
+    let (swaps, truncated) = match query_swaps(chain, from, pairs, max_swaps) {
+        Ok(swaps) => swaps,
+        Err(error) => {
+            eprintln!("Error while querying swaps: {:#?}", error);
+            return;
+        }
+    };
+    if truncated {
+        let covered = match swaps.last() {
+            Some(last) => last.timestamp.saturating_sub(from) as f64 / 3_600.0,
+            None => 0.0,
+        };
+        println!("Only the first {max_swaps} swaps were fetched, covering {covered:.1} of {hours} hours, raise --max-swaps to cover the whole window.");
+    }
+    let volume: f64 = swaps.iter().map(|swap| swap.amount_usd).sum();
+
+    print_summary(&swaps, volume);
+    print_sizes(&swaps, volume);
+    print_addresses("Top recipients", &swaps, volume, limit, |swap| &swap.to);
+    print_addresses(
+        "Top senders (routers, aggregators)",
+        &swaps,
+        volume,
+        limit,
+        |swap| &swap.sender,
+    );
+    print_largest(&swaps, limit);
+}
@@ -0,0 +1,357 @@
+use std::{cmp::Ordering, collections::HashMap};
+
+use clap::{Arg, ArgMatches, Command};
+use cli_table::{print_stdout, Cell, CellStruct, Table};
+
+use crate::{
+    helpers::{
+        block::block_timestamp::{now, BlockSource},
+        time::{format_date, format_timestamp},
+        volume::{
+            period_volume::{
+                query_chain_period_volume, query_period_volume_multichain, Pair, Period,
+                PeriodVolume,
+            },
+            snapshots::{
+                query_factory_snapshots_multichain, query_pair_snapshots, sum_by_date, Granularity,
+                Snapshot,
+            },
+        },
+    },
+    network::{LEGACY_SUBGRAPH, V3_SUBGRAPH},
+    scli::args::{parse_token_list_config, token_list_args},
+};
+
+pub fn command() -> Command {
+    let network_arg = Arg::new("network")
+        .help("Network to query, all networks if not set. Ex: ethereum.")
+        .short('n')
+        .long("network");
+    let days_arg = Arg::new("days")
+        .help("Days in the past to query.")
+        .short('d')
+        .long("days")
+        .default_value("7")
+        .value_parser(clap::value_parser!(u32).range(1..=30));
+    let group_by_arg = Arg::new("group-by")
+        .help("Group volume by chain, pair, token (pair volume split half/half across its two tokens) or date. Dates come from the legacy/trident factory snapshots, which cover every pair but not V3.")
+        .long("group-by")
+        .value_parser(["chain", "pair", "token", "day"])
+        .default_value("chain");
+    let granularity_arg = Arg::new("granularity")
+        .help("Length of the periods listed with --group-by day.")
+        .long("granularity")
+        .value_parser(["day", "hour"])
+        .default_value("day");
+    let pair_arg = Arg::new("pair")
+        .help("With --group-by day, list the snapshots of a single pair instead of the whole chain. Requires --network.")
+        .long("pair")
+        .requires("network");
+    let limit_arg = Arg::new("limit")
+        .help("Max number of pairs or tokens to print.")
+        .long("limit")
+        .default_value("20")
+        .value_parser(clap::value_parser!(usize));
+
+    Command::new("volume")
+        .about("Get volume and fees over a period, without revenue computation.")
+        .arg(network_arg)
+        .arg(days_arg)
+        .arg(group_by_arg)
+        .arg(granularity_arg)
+        .arg(pair_arg)
+        .arg(limit_arg)
+        .args(token_list_args())
+}
+
+/// Print the token list each chain volume was filtered with, so reports show what they cover.
+pub(super) fn print_token_lists<'a>(volumes: impl Iterator<Item = (&'a String, &'a PeriodVolume)>) {
+    let mut lists: Vec<String> = vec![];
+    let mut fallbacks: Vec<&str> = vec![];
+    for (chain, volume) in volumes {
+        lists.push(format!("{chain}: {}", volume.token_list.describe()));
+        if volume.token_list.fallback {
+            fallbacks.push(chain);
+        }
+    }
+    lists.sort();
+    println!("Token lists used: {}", lists.join(" | "));
+    if fallbacks.is_empty() {
+        return;
+    }
+    fallbacks.sort();
+    println!(
+        "WARNING: remote token list unreachable on {}, totals above only count the core tokens of the bundled snapshot (or every pair without one) and aren't comparable with remote list ones.",
+        fallbacks.join(", ")
+    );
+}
+
+/// Warn about V3 pools whose protocol fee couldn't be read, their treasury fees come from the
+/// fee models instead of the pool contract.
+pub(super) fn print_unread_protocol_fees<'a>(
+    volumes: impl Iterator<Item = (&'a String, &'a PeriodVolume)>,
+) {
+    let mut unread: Vec<String> = volumes
+        .filter(|(_, volume)| !volume.unread_protocol_fees.is_empty())
+        .map(|(chain, volume)| format!("{chain}: {}", volume.unread_protocol_fees.join(", ")))
+        .collect();
+    if unread.is_empty() {
+        return;
+    }
+    unread.sort();
+    println!(
+        "Protocol fee unread, treasury fees estimated from the fee models for: {}",
+        unread.join(" | ")
+    );
+}
+
+fn sort_by_volume<T>(rows: &mut [T], volume: impl Fn(&T) -> f64) {
+    rows.sort_by(|a, b| {
+        if volume(a) > volume(b) {
+            return Ordering::Less;
+        }
+        Ordering::Greater
+    });
+}
+
+fn print_chains(volumes: &HashMap<String, PeriodVolume>) {
+    let mut chains: Vec<(&String, &PeriodVolume, f64, f64)> = volumes
+        .iter()
+        .map(|(chain, volume)| {
+            let (total_volume, total_fees) = volume.pairs.values().fold((0.0, 0.0), |acc, pair| {
+                (acc.0 + pair.volume_usd, acc.1 + pair.fees_usd)
+            });
+            (chain, volume, total_volume, total_fees)
+        })
+        .collect();
+    sort_by_volume(&mut chains, |chain| chain.2);
+
+    let (total_volume, total_fees) = chains
+        .iter()
+        .fold((0.0, 0.0), |acc, chain| (acc.0 + chain.2, acc.1 + chain.3));
+    let mut table: Vec<Vec<CellStruct>> = chains
+        .iter()
+        .map(|(chain, volume, total_volume, total_fees)| {
+            vec![
+                chain.cell(),
+                volume.start_block.cell(),
+                volume.end_block.cell(),
+                volume.pairs.len().cell(),
+                format!("{} $", total_volume.round()).cell(),
+                format!("{} $", total_fees.round()).cell(),
+            ]
+        })
+        .collect();
+    table.push(vec![
+        "Total".cell(),
+        "".cell(),
+        "".cell(),
+        "".cell(),
+        format!("{} $", total_volume.round()).cell(),
+        format!("{} $", total_fees.round()).cell(),
+    ]);
+    let table = table.table().title(vec![
+        "Chain".cell(),
+        "Start block".cell(),
+        "End block".cell(),
+        "Pairs".cell(),
+        "Volume".cell(),
+        "Fees".cell(),
+    ]);
+    print_stdout(table).expect("Error while pinting result.");
+}
+
+fn print_pairs(volumes: &HashMap<String, PeriodVolume>, limit: usize) {
+    let mut pairs: Vec<(&String, &Pair)> = volumes
+        .iter()
+        .flat_map(|(chain, volume)| volume.pairs.values().map(move |pair| (chain, pair)))
+        .collect();
+    sort_by_volume(&mut pairs, |pair| pair.1.volume_usd);
+
+    let table: Vec<Vec<CellStruct>> = pairs
+        .iter()
+        .take(limit)
+        .map(|(chain, pair)| {
+            vec![
+                chain.cell(),
+                pair.name.as_str().cell(),
+                pair.id.as_str().cell(),
+                format!("{:?}", pair.protocol).cell(),
+                format!("{} $", pair.volume_usd.round()).cell(),
+                format!("{} $", pair.fees_usd.round()).cell(),
+                format!("{} $", pair.liquidity_usd.round()).cell(),
+            ]
+        })
+        .collect();
+    let table = table.table().title(vec![
+        "Chain".cell(),
+        "Pair".cell(),
+        "Address".cell(),
+        "Protocol".cell(),
+        "Volume".cell(),
+        "Fees".cell(),
+        "Liquidity".cell(),
+    ]);
+    print_stdout(table).expect("Error while pinting result.");
+}
+
+#[derive(Default)]
+struct TokenVolume {
+    chains: Vec<String>,
+    pairs: usize,
+    volume: f64,
+    fees: f64,
+}
+
+fn print_tokens(volumes: &HashMap<String, PeriodVolume>, limit: usize) {
+    let mut tokens: HashMap<String, TokenVolume> = HashMap::new();
+    for (chain, volume) in volumes {
+        for pair in volume.pairs.values() {
+            for token in [&pair.token0, &pair.token1] {
+                let token_volume = tokens.entry(token.symbol.clone()).or_default();
+                if !token_volume.chains.contains(chain) {
+                    token_volume.chains.push(chain.clone());
+                }
+                token_volume.pairs += 1;
+                //split half/half as in revenues --group-by token
+                token_volume.volume += pair.volume_usd / 2.0;
+                token_volume.fees += pair.fees_usd / 2.0;
+            }
+        }
+    }
+    let mut tokens: Vec<(String, TokenVolume)> = tokens.into_iter().collect();
+    sort_by_volume(&mut tokens, |token| token.1.volume);
+
+    let table: Vec<Vec<CellStruct>> = tokens
+        .iter()
+        .take(limit)
+        .map(|(symbol, token)| {
+            vec![
+                symbol.cell(),
+                token.chains.join(", ").cell(),
+                token.pairs.cell(),
+                format!("{} $", token.volume.round()).cell(),
+                format!("{} $", token.fees.round()).cell(),
+            ]
+        })
+        .collect();
+    let table = table.table().title(vec![
+        "Token".cell(),
+        "Chains".cell(),
+        "Pairs".cell(),
+        "Volume".cell(),
+        "Fees".cell(),
+    ]);
+    print_stdout(table).expect("Error while pinting result.");
+}
+
+fn print_snapshots(snapshots: &[Snapshot], granularity: Granularity) {
+    let table: Vec<Vec<CellStruct>> = snapshots
+        .iter()
+        .map(|snapshot| {
+            vec![
+                match granularity {
+                    Granularity::Day => format_date(snapshot.date),
+                    Granularity::Hour => format_timestamp(snapshot.date),
+                }
+                .cell(),
+                format!("{} $", snapshot.volume_usd.round()).cell(),
+                format!("{} $", snapshot.fees_usd.round()).cell(),
+                format!("{} $", snapshot.liquidity_usd.round()).cell(),
+                snapshot.tx_count.cell(),
+            ]
+        })
+        .collect();
+    let table = table.table().title(vec![
+        "Date".cell(),
+        "Volume".cell(),
+        "Fees".cell(),
+        "Liquidity".cell(),
+        "Transactions".cell(),
+    ]);
+    print_stdout(table).expect("Error while pinting result.");
+}
+
+fn execute_snapshots(params: &ArgMatches, days: u32) {
+    let granularity = match params.get_one::<String>("granularity").unwrap().as_str() {
+        "hour" => Granularity::Hour,
+        _ => Granularity::Day, //default to day
+    };
+    let from = now().saturating_sub(u64::from(86_400 * days));
+    let network = params.get_one::<String>("network");
+
+    let snapshots = match (network, params.get_one::<String>("pair")) {
+        (Some(chain), Some(pair)) => match query_pair_snapshots(chain, pair, from, granularity) {
+            Ok(snapshots) => snapshots,
+            Err(error) => {
+                eprintln!("Error while querying snapshots: {:#?}", error);
+                return;
+            }
+        },
+        _ => {
+            let chains: Vec<String> = match network {
+                Some(chain) => vec![chain.clone()],
+                None => LEGACY_SUBGRAPH
+                    .keys()
+                    .map(|chain| chain.to_string())
+                    .collect(),
+            };
+            let snapshots: Vec<Snapshot> =
+                query_factory_snapshots_multichain(chains, from, granularity)
+                    .into_values()
+                    .flatten()
+                    .collect();
+            sum_by_date(&snapshots)
+        }
+    };
+    print_snapshots(&snapshots, granularity);
+}
+
+pub fn execute(params: &ArgMatches) {
+    let days = *params.get_one::<u32>("days").unwrap(); //default to 7
+    let group_by = params.get_one::<String>("group-by").unwrap(); //default to chain
+    if group_by == "day" {
+        return execute_snapshots(params, days);
+    }
+    if params.contains_id("pair") {
+        eprintln!("--pair only applies with --group-by day.");
+        return;
+    }
+    let period = Period {
+        days,
+        block_source: BlockSource::Subgraph,
+        token_list: parse_token_list_config(params),
+    };
+    let limit = *params.get_one::<usize>("limit").unwrap(); //default to 20
+
+    let volumes: HashMap<String, PeriodVolume> = match params.get_one::<String>("network") {
+        Some(chain) => {
+            match query_chain_period_volume(chain.clone(), &period, Default::default()) {
+                Ok(volume) => HashMap::from([(chain.clone(), volume)]),
+                Err(error) => {
+                    eprintln!("Error while querying volume: {:#?}", error);
+                    return;
+                }
+            }
+        }
+        None => {
+            let mut chains: Vec<String> = LEGACY_SUBGRAPH
+                .keys()
+                .map(|chain| chain.to_string())
+                .collect();
+            for chain in V3_SUBGRAPH.keys() {
+                if !LEGACY_SUBGRAPH.contains_key(chain) {
+                    chains.push(chain.to_string());
+                }
+            }
+            query_period_volume_multichain(chains, period, &HashMap::new())
+        }
+    };
+
+    match group_by.as_str() {
+        "pair" => print_pairs(&volumes, limit),
+        "token" => print_tokens(&volumes, limit),
+        _ => print_chains(&volumes),
+    }
+    print_token_lists(volumes.iter());
+}